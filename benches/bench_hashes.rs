@@ -1,7 +1,8 @@
-use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
-#[cfg(target_arch = "x86_64")]
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 use highway::{AvxHash, SseHash};
-use highway::{HighwayHash, HighwayHasher, Key, PortableHash};
+use highway::{HighwayBuildHasher, HighwayHash, HighwayHasher, Key, Portable32Hash, PortableHash};
+use std::hash::{BuildHasher, Hasher};
 
 fn bit64_hash(c: &mut Criterion) {
     let parameters = [1, 4, 16, 64, 256, 1024, 4096, 16384, 65536];
@@ -20,7 +21,12 @@ fn bit64_hash(c: &mut Criterion) {
             b.iter(|| PortableHash::new(key).hash64(&data))
         });
 
-        #[cfg(target_arch = "x86_64")]
+        group.bench_with_input(BenchmarkId::new("portable32", i), i, |b, param| {
+            let data = vec![0u8; *param];
+            b.iter(|| Portable32Hash::new(key).hash64(&data))
+        });
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
         {
             let key = Key([0, 0, 0, 0]);
             if AvxHash::new(key).is_some() {
@@ -53,7 +59,12 @@ fn bit256_hash(c: &mut Criterion) {
             b.iter(|| PortableHash::new(key).hash256(&data))
         });
 
-        #[cfg(target_arch = "x86_64")]
+        group.bench_with_input(BenchmarkId::new("portable32", i), i, |b, param| {
+            let data = vec![0u8; *param];
+            b.iter(|| Portable32Hash::new(key).hash256(&data))
+        });
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
         {
             if AvxHash::new(key).is_some() {
                 group.bench_with_input(BenchmarkId::new("avx", i), i, |b, param| {
@@ -73,5 +84,64 @@ fn bit256_hash(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, bit64_hash, bit256_hash);
+fn hasher_ints(c: &mut Criterion) {
+    let mut group = c.benchmark_group("hasher");
+    let highway = HighwayBuildHasher::default();
+
+    group.bench_function("write-u8", |b| {
+        b.iter(|| {
+            let mut hasher = highway.build_hasher();
+            hasher.write_u8(black_box(0xef));
+            hasher.finish()
+        })
+    });
+
+    group.bench_function("write-u16", |b| {
+        b.iter(|| {
+            let mut hasher = highway.build_hasher();
+            hasher.write_u16(black_box(0xbeef));
+            hasher.finish()
+        })
+    });
+
+    group.bench_function("write-u64", |b| {
+        b.iter(|| {
+            let mut hasher = highway.build_hasher();
+            hasher.write_u64(black_box(0xdead_beef));
+            hasher.finish()
+        })
+    });
+
+    group.bench_function("write-bytes", |b| {
+        b.iter(|| {
+            let mut hasher = highway.build_hasher();
+            hasher.write(&black_box(0xdead_beef_u64).to_ne_bytes());
+            hasher.finish()
+        })
+    });
+
+    group.bench_function("write-u64x4", |b| {
+        b.iter(|| {
+            let mut hasher = highway.build_hasher();
+            for i in 0..4u64 {
+                hasher.write_u64(black_box(i));
+            }
+            hasher.finish()
+        })
+    });
+
+    group.bench_function("write-bytesx4", |b| {
+        b.iter(|| {
+            let mut hasher = highway.build_hasher();
+            for i in 0..4u64 {
+                hasher.write(&black_box(i).to_ne_bytes());
+            }
+            hasher.finish()
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bit64_hash, bit256_hash, hasher_ints);
 criterion_main!(benches);
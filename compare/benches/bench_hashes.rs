@@ -1,6 +1,6 @@
 use blake2b_simd::Params;
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
-#[cfg(target_arch = "x86_64")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 use highway::{AvxHash, SseHash};
 use highway::{HighwayHash, Key, PortableHash};
 use sha2::{Digest, Sha256};
@@ -69,7 +69,7 @@ fn bit64_hash(c: &mut Criterion) {
         });
 
 
-        #[cfg(target_arch = "x86_64")]
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
         {
             if AvxHash::new(key).is_some() {
                 group.bench_with_input(BenchmarkId::new("avx", i), i, |b, param| {
@@ -126,7 +126,7 @@ fn bit256_hash(c: &mut Criterion) {
             })
         });
 
-        #[cfg(target_arch = "x86_64")]
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
         {
             if AvxHash::new(key).is_some() {
                 group.bench_with_input(BenchmarkId::new("avx", i), i, |b, param| {
@@ -18,7 +18,7 @@ fn main() {
     let hasher = PortableHash::default();
     println!("{}", hash_data(hasher, &data));
 
-    #[cfg(target_arch = "x86_64")]
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     {
         if let Some(hasher) = highway::AvxHash::new(highway::Key::default()) {
             println!("{}", hash_data(hasher, &data));
@@ -1,10 +1,10 @@
 #![no_main]
 
-use highway::{HighwayHash, HighwayHasher, Key, PortableHash};
+use highway::{HighwayHash, HighwayHasher, Key, Portable32Hash, PortableHash};
 use libc::size_t;
 use libfuzzer_sys::arbitrary;
 
-#[cfg(target_arch = "x86_64")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 use highway::{AvxHash, SseHash};
 
 #[cfg(target_arch = "aarch64")]
@@ -37,11 +37,15 @@ libfuzzer_sys::fuzz_target!(|input: FuzzKey| {
         let builder128 = HighwayHasher::new(key).hash128(data);
         let builder256 = HighwayHasher::new(key).hash256(data);
 
+        assert_eq!(Portable32Hash::new(key).hash64(data), portable64);
+        assert_eq!(Portable32Hash::new(key).hash128(data), portable128);
+        assert_eq!(Portable32Hash::new(key).hash256(data), portable256);
+
         assert_eq!(builder64, portable64);
         assert_eq!(builder128, portable128);
         assert_eq!(builder256, portable256);
 
-        #[cfg(target_arch = "x86_64")]
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
         {
             if let Some(hash) = AvxHash::new(key).map(|x| x.hash64(data)) {
                 assert_eq!(hash, portable64)
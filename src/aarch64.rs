@@ -1,13 +1,14 @@
 #![allow(unsafe_code)]
 use crate::internal::{unordered_load3, HashPacket, PACKET_SIZE};
-use crate::{HighwayHash, Key, PortableHash};
+use crate::{HighwayHash, HighwayHashReset, Key, PortableHash, ResetError};
 use core::arch::aarch64::*;
 use core::ops::{
-    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, SubAssign,
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref,
+    SubAssign,
 };
 
 /// HighwayHash powered by Neon instructions
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct NeonHash {
     buffer: HashPacket,
     v0L: V2x64U,
@@ -18,6 +19,7 @@ pub struct NeonHash {
     mul0H: V2x64U,
     mul1L: V2x64U,
     mul1H: V2x64U,
+    pub(crate) key: Option<Key>,
 }
 
 impl HighwayHash for NeonHash {
@@ -28,6 +30,13 @@ impl HighwayHash for NeonHash {
         }
     }
 
+    #[inline]
+    fn append_vectored<T: Deref<Target = [u8]>>(&mut self, fragments: &[T]) {
+        unsafe {
+            self.append_vectored(fragments);
+        }
+    }
+
     #[inline]
     fn finalize64(mut self) -> u64 {
         unsafe { Self::finalize64(&mut self) }
@@ -67,11 +76,57 @@ impl HighwayHash for NeonHash {
             mul0,
             mul1,
             buffer: self.buffer,
+            key: self.key,
         }
         .checkpoint()
     }
 }
 
+impl Default for NeonHash {
+    fn default() -> Self {
+        // Like `HighwayHasher`, assume every aarch64 target is neon capable
+        unsafe { NeonHash::force_new(Key::default()) }
+    }
+}
+
+impl HighwayHashReset for NeonHash {
+    #[inline]
+    fn finalize64_reset(&mut self) -> Result<u64, ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        let hash = unsafe { Self::finalize64(self) };
+        self.rekey(key);
+        Ok(hash)
+    }
+
+    #[inline]
+    fn finalize128_reset(&mut self) -> Result<[u64; 2], ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        let hash = unsafe { Self::finalize128(self) };
+        self.rekey(key);
+        Ok(hash)
+    }
+
+    #[inline]
+    fn finalize256_reset(&mut self) -> Result<[u64; 4], ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        let hash = unsafe { Self::finalize256(self) };
+        self.rekey(key);
+        Ok(hash)
+    }
+
+    #[inline]
+    fn reset(&mut self) -> Result<(), ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        self.rekey(key);
+        Ok(())
+    }
+
+    #[inline]
+    fn rekey(&mut self, key: Key) {
+        *self = unsafe { Self::force_new(key) };
+    }
+}
+
 impl NeonHash {
     /// Creates a new `NeonHash` while circumventing any runtime checks.
     ///
@@ -99,6 +154,7 @@ impl NeonHash {
             mul1L: init1L,
             mul1H: init1H,
             buffer: HashPacket::default(),
+            key: Some(key),
         }
     }
 
@@ -109,7 +165,25 @@ impl NeonHash {
     /// See [`Self::force_new`] for safety concerns.
     #[must_use]
     pub unsafe fn force_from_checkpoint(data: [u8; 164]) -> Self {
-        let portable = PortableHash::from_checkpoint(data);
+        Self::force_from_portable(&PortableHash::from_checkpoint(data))
+    }
+
+    /// Creates a new `NeonHash` from a checkpoint and the key it was created with. A checkpoint
+    /// does not record the key, so supplying it here lets [`HighwayHashReset::reset`] restore
+    /// the keyed initial state.
+    ///
+    /// # Safety
+    ///
+    /// See [`Self::force_new`] for safety concerns.
+    #[must_use]
+    pub unsafe fn force_from_checkpoint_with_key(data: [u8; 164], key: Key) -> Self {
+        Self {
+            key: Some(key),
+            ..Self::force_from_checkpoint(data)
+        }
+    }
+
+    pub(crate) unsafe fn force_from_portable(portable: &PortableHash) -> Self {
         NeonHash {
             v0L: V2x64U::new(portable.v0[1], portable.v0[0]),
             v0H: V2x64U::new(portable.v0[3], portable.v0[2]),
@@ -120,6 +194,7 @@ impl NeonHash {
             mul1L: V2x64U::new(portable.mul1[1], portable.mul1[0]),
             mul1H: V2x64U::new(portable.mul1[3], portable.mul1[2]),
             buffer: portable.buffer,
+            key: portable.key,
         }
     }
 
@@ -319,8 +394,18 @@ impl NeonHash {
             self.buffer.set_to(chunks.remainder());
         }
     }
+
+    #[target_feature(enable = "neon")]
+    unsafe fn append_vectored<T: Deref<Target = [u8]>>(&mut self, fragments: &[T]) {
+        let mut buffer = self.buffer;
+        buffer.assemble(fragments, |packet| self.update(Self::data_to_lanes(packet)));
+        self.buffer = buffer;
+    }
 }
 
+impl_write!(NeonHash);
+impl_hasher!(NeonHash);
+
 #[inline]
 fn take<const N: usize>(data: &[u8]) -> [u8; N] {
     debug_assert!(data.len() >= N);
@@ -0,0 +1,53 @@
+//! The read loop shared by the tokio and futures flavors of `hash_async_reader`.
+
+use crate::{HighwayHash, HighwayHasher, Key};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::io;
+
+const BUFFER_SIZE: usize = 8 * 1024;
+
+/// An asynchronous reader that fills a byte slice, implemented by thin wrappers around the
+/// tokio and futures `AsyncRead` traits.
+pub(crate) trait PollRead: Unpin {
+    fn poll_read_into(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<io::Result<usize>>;
+}
+
+/// Reads until EOF and returns the 256bit hash of everything read
+pub(crate) async fn hash_reader<R: PollRead>(mut reader: R, key: Key) -> io::Result<[u64; 4]> {
+    let mut hasher = HighwayHasher::new(key);
+    let mut buf = vec![0u8; BUFFER_SIZE];
+    loop {
+        let read = ReadChunk {
+            reader: &mut reader,
+            buf: &mut buf,
+        }
+        .await?;
+
+        if read == 0 {
+            return Ok(hasher.finalize256());
+        }
+
+        hasher.append(&buf[..read]);
+    }
+}
+
+struct ReadChunk<'a, R> {
+    reader: &'a mut R,
+    buf: &'a mut [u8],
+}
+
+impl<R: PollRead> Future for ReadChunk<'_, R> {
+    type Output = io::Result<usize>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        loop {
+            match this.reader.poll_read_into(cx, this.buf) {
+                Poll::Ready(Err(e)) if e.kind() == io::ErrorKind::Interrupted => continue,
+                x => return x,
+            }
+        }
+    }
+}
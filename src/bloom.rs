@@ -0,0 +1,527 @@
+//! Hash families and bloom filters that derive every index from a single keyed hash.
+//!
+//! Probabilistic data structures need `k` independent hash functions. Rather than hashing an
+//! item `k` times under different keys, [`HashFamily`] hashes it once with `finalize256` and
+//! derives as many indices as needed with enhanced double hashing, or draws them from the
+//! extendable output of [`XofReader`] when truly independent indices are wanted.
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use highway::bloom::BloomFilter;
+//! use highway::Key;
+//!
+//! let mut filter = BloomFilter::new(Key([1, 2, 3, 4]), 1024, 5);
+//! filter.insert(b"hello");
+//! assert!(filter.contains(b"hello"));
+//!
+//! // Filters can be shipped to another machine and queried there. The key isn't part of the
+//! // serialized bytes, so the receiver must already hold it.
+//! let restored = BloomFilter::from_bytes(Key([1, 2, 3, 4]), &filter.to_bytes()).unwrap();
+//! assert!(restored.contains(b"hello"));
+//! # }
+//! ```
+
+use crate::builder::{HighwayHasher, PreparedKey};
+use crate::key::Key;
+use crate::traits::HighwayHash;
+use crate::xof::XofReader;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use core::fmt;
+
+/// Derives any number of indices into a table of `m` slots from a single keyed hash
+#[derive(Debug, Clone)]
+pub struct HashFamily {
+    key: PreparedKey,
+}
+
+impl HashFamily {
+    /// Creates a family of hash functions keyed with `key`
+    #[must_use]
+    pub fn new(key: Key) -> Self {
+        HashFamily {
+            key: PreparedKey::new(key),
+        }
+    }
+
+    /// The key of the family
+    #[must_use]
+    pub fn key(&self) -> Key {
+        self.key.key()
+    }
+
+    /// Returns `k` indices in `0..m` for `data` using enhanced double hashing over the 256bit
+    /// hash: the low and high 128 bits seed the start and step of the probe sequence.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is zero
+    #[must_use]
+    pub fn indices(&self, data: &[u8], k: usize, m: u64) -> Indices {
+        Indices::from_hash(self.key.hash256(data), k, m)
+    }
+
+    /// Returns an endless sequence of independent indices in `0..m` for `data`, drawn from the
+    /// extendable output of the hash. Slower than [`HashFamily::indices`], but indices are not
+    /// correlated with each other.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is zero
+    #[must_use]
+    pub fn xof_indices(&self, data: &[u8], m: u64) -> XofIndices {
+        assert!(m != 0, "table size must be non-zero");
+        let mut hasher: HighwayHasher = self.key.hasher();
+        hasher.append(data);
+        XofIndices {
+            reader: hasher.finalize_xof(),
+            m,
+        }
+    }
+}
+
+/// Indices produced by enhanced double hashing. See [`HashFamily::indices`]
+#[derive(Debug, Clone)]
+pub struct Indices {
+    x: u64,
+    y: u64,
+    m: u64,
+    i: u64,
+    k: usize,
+}
+
+impl Indices {
+    /// Derives `k` indices in `0..m` from an already computed 256bit hash
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is zero
+    #[must_use]
+    pub fn from_hash(hash: [u64; 4], k: usize, m: u64) -> Self {
+        assert!(m != 0, "table size must be non-zero");
+        let low = u128::from(hash[0]) | (u128::from(hash[1]) << 64);
+        let high = u128::from(hash[2]) | (u128::from(hash[3]) << 64);
+        let m128 = u128::from(m);
+        Indices {
+            x: (low % m128) as u64,
+            y: (high % m128) as u64,
+            m,
+            i: 0,
+            k,
+        }
+    }
+}
+
+impl Iterator for Indices {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if self.k == 0 {
+            return None;
+        }
+
+        let result = self.x;
+        self.k -= 1;
+        self.x = add_mod(self.x, self.y, self.m);
+        self.y = add_mod(self.y, self.i % self.m, self.m);
+        self.i += 1;
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.k, Some(self.k))
+    }
+}
+
+impl ExactSizeIterator for Indices {}
+
+/// Independent indices drawn from extendable output. See [`HashFamily::xof_indices`]
+#[derive(Debug, Clone)]
+pub struct XofIndices {
+    reader: XofReader,
+    m: u64,
+}
+
+impl Iterator for XofIndices {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let mut bytes = [0u8; 8];
+        self.reader.fill(&mut bytes);
+        let x = u128::from(u64::from_le_bytes(bytes));
+        Some(((x * u128::from(self.m)) >> 64) as u64)
+    }
+}
+
+/// `(a + b) % m` for `a, b < m` without overflow
+fn add_mod(a: u64, b: u64, m: u64) -> u64 {
+    let (sum, overflow) = a.overflowing_add(b);
+    if overflow || sum >= m {
+        sum.wrapping_sub(m)
+    } else {
+        sum
+    }
+}
+
+/// Error returned when deserializing a filter from malformed bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FromBytesError(());
+
+impl fmt::Display for FromBytesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid serialized filter")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FromBytesError {}
+
+/// The most hashes per item a filter accepts. Beyond this the false positive rate is already
+/// far below anything `f64` can express, so larger values only come from corrupt input.
+pub const MAX_HASHES: u32 = 64;
+
+/// Serialized layout shared by the filters: a 4 byte magic, then the number of slots and the
+/// number of hashes, both little endian, followed by the table. The key is deliberately left
+/// out so that publishing a filter doesn't disclose it.
+#[cfg(feature = "alloc")]
+const HEADER_SIZE: usize = 4 + 8 + 4;
+
+#[cfg(feature = "alloc")]
+fn write_header(magic: &[u8; 4], slots: u64, hashes: u32, capacity: usize) -> Vec<u8> {
+    let mut out = Vec::with_capacity(HEADER_SIZE + capacity);
+    out.extend_from_slice(magic);
+    out.extend_from_slice(&slots.to_le_bytes());
+    out.extend_from_slice(&hashes.to_le_bytes());
+    out
+}
+
+#[cfg(feature = "alloc")]
+fn read_header<'a>(
+    magic: &[u8; 4],
+    data: &'a [u8],
+) -> Result<(u64, u32, &'a [u8]), FromBytesError> {
+    if data.len() < HEADER_SIZE || &data[..4] != magic {
+        return Err(FromBytesError(()));
+    }
+
+    let mut slots = [0u8; 8];
+    slots.copy_from_slice(&data[4..12]);
+    let slots = u64::from_le_bytes(slots);
+    let mut hashes = [0u8; 4];
+    hashes.copy_from_slice(&data[12..16]);
+    let hashes = u32::from_le_bytes(hashes);
+    if slots == 0 || !valid_num_hashes(hashes) {
+        return Err(FromBytesError(()));
+    }
+
+    Ok((slots, hashes, &data[HEADER_SIZE..]))
+}
+
+#[cfg(feature = "alloc")]
+fn valid_num_hashes(num_hashes: u32) -> bool {
+    (1..=MAX_HASHES).contains(&num_hashes)
+}
+
+/// Returns the number of slots and hashes for a filter holding `items` with the given false
+/// positive rate
+///
+/// # Panics
+///
+/// Panics if `false_positive_rate` isn't strictly between zero and one
+#[cfg(feature = "std")]
+fn optimal_parameters(items: usize, false_positive_rate: f64) -> (u64, u32) {
+    assert!(
+        false_positive_rate > 0.0 && false_positive_rate < 1.0,
+        "false positive rate must be between zero and one"
+    );
+    let ln2 = core::f64::consts::LN_2;
+    let items = (items as f64).max(1.0);
+    let slots = (-(items * false_positive_rate.ln()) / (ln2 * ln2))
+        .ceil()
+        .max(1.0);
+    let hashes = ((slots / items) * ln2)
+        .round()
+        .clamp(1.0, f64::from(MAX_HASHES));
+    (slots as u64, hashes as u32)
+}
+
+/// A bloom filter keyed with a [`Key`]
+///
+/// All `k` bit positions of an item are derived from one 256bit hash via
+/// [`HashFamily::indices`]. A filter built on one machine can be serialized and queried on
+/// another that holds the same key, as the key is not part of the serialized form.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct BloomFilter {
+    family: HashFamily,
+    bits: Vec<u64>,
+    num_bits: u64,
+    num_hashes: u32,
+}
+
+#[cfg(feature = "alloc")]
+impl BloomFilter {
+    const MAGIC: &'static [u8; 4] = b"HWBF";
+
+    /// The number of `u64` words backing `num_bits` bits
+    fn words(num_bits: u64) -> u64 {
+        num_bits / 64 + u64::from(num_bits & 63 != 0)
+    }
+
+    /// Creates an empty filter of `num_bits` bits that sets `num_hashes` bits per item
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_bits` is zero, or if `num_hashes` is zero or greater than [`MAX_HASHES`]
+    #[must_use]
+    pub fn new(key: Key, num_bits: u64, num_hashes: u32) -> Self {
+        assert!(num_bits != 0, "filter must have at least one bit");
+        assert!(valid_num_hashes(num_hashes), "invalid number of hashes");
+        let words = Self::words(num_bits);
+        BloomFilter {
+            family: HashFamily::new(key),
+            bits: vec![0; words as usize],
+            num_bits,
+            num_hashes,
+        }
+    }
+
+    /// Creates an empty filter sized to hold `items` with the given false positive rate
+    ///
+    /// # Panics
+    ///
+    /// Panics if `false_positive_rate` isn't strictly between zero and one
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn with_false_positive_rate(key: Key, items: usize, false_positive_rate: f64) -> Self {
+        let (num_bits, num_hashes) = optimal_parameters(items, false_positive_rate);
+        BloomFilter::new(key, num_bits, num_hashes)
+    }
+
+    /// The key of the filter
+    #[must_use]
+    pub fn key(&self) -> Key {
+        self.family.key()
+    }
+
+    /// The number of bits in the filter
+    #[must_use]
+    pub fn num_bits(&self) -> u64 {
+        self.num_bits
+    }
+
+    /// The number of bits set per item
+    #[must_use]
+    pub fn num_hashes(&self) -> u32 {
+        self.num_hashes
+    }
+
+    fn indices(&self, item: &[u8]) -> Indices {
+        self.family
+            .indices(item, self.num_hashes as usize, self.num_bits)
+    }
+
+    /// Adds an item to the filter
+    pub fn insert(&mut self, item: &[u8]) {
+        for index in self.indices(item) {
+            self.bits[(index / 64) as usize] |= 1 << (index % 64);
+        }
+    }
+
+    /// Returns false if the item was never inserted, and true if it probably was
+    #[must_use]
+    pub fn contains(&self, item: &[u8]) -> bool {
+        self.indices(item)
+            .all(|index| self.bits[(index / 64) as usize] & (1 << (index % 64)) != 0)
+    }
+
+    /// Removes all items from the filter
+    pub fn clear(&mut self) {
+        self.bits.iter_mut().for_each(|word| *word = 0);
+    }
+
+    /// Serializes the filter. The key is not included and must be passed to
+    /// [`BloomFilter::from_bytes`] separately.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = write_header(
+            Self::MAGIC,
+            self.num_bits,
+            self.num_hashes,
+            self.bits.len() * 8,
+        );
+        for word in &self.bits {
+            out.extend_from_slice(&word.to_le_bytes());
+        }
+        out
+    }
+
+    /// Deserializes a filter written by [`BloomFilter::to_bytes`] that was keyed with `key`
+    ///
+    /// A different key is not detected, the filter just won't recognize the inserted items.
+    pub fn from_bytes(key: Key, data: &[u8]) -> Result<Self, FromBytesError> {
+        let (num_bits, num_hashes, table) = read_header(Self::MAGIC, data)?;
+        if table.len() as u64 != Self::words(num_bits) * 8 {
+            return Err(FromBytesError(()));
+        }
+
+        let mut filter = BloomFilter::new(key, num_bits, num_hashes);
+
+        for (word, chunk) in filter.bits.iter_mut().zip(table.chunks_exact(8)) {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(chunk);
+            *word = u64::from_le_bytes(bytes);
+        }
+        Ok(filter)
+    }
+}
+
+/// A counting bloom filter keyed with a [`Key`], which supports removal. Like [`BloomFilter`],
+/// its serialized form leaves out the key.
+///
+/// Each slot is an 8 bit counter. Counters saturate at 255 and are never decremented from
+/// there, so an overflowing slot can't cause false negatives.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct CountingBloomFilter {
+    family: HashFamily,
+    counters: Vec<u8>,
+    num_hashes: u32,
+}
+
+#[cfg(feature = "alloc")]
+impl CountingBloomFilter {
+    const MAGIC: &'static [u8; 4] = b"HWCB";
+
+    /// Creates an empty filter of `num_counters` counters that increments `num_hashes`
+    /// counters per item
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_counters` is zero, or if `num_hashes` is zero or greater than
+    /// [`MAX_HASHES`]
+    #[must_use]
+    pub fn new(key: Key, num_counters: usize, num_hashes: u32) -> Self {
+        assert!(num_counters != 0, "filter must have at least one counter");
+        assert!(valid_num_hashes(num_hashes), "invalid number of hashes");
+        CountingBloomFilter {
+            family: HashFamily::new(key),
+            counters: vec![0; num_counters],
+            num_hashes,
+        }
+    }
+
+    /// Creates an empty filter sized to hold `items` with the given false positive rate
+    ///
+    /// # Panics
+    ///
+    /// Panics if `false_positive_rate` isn't strictly between zero and one
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn with_false_positive_rate(key: Key, items: usize, false_positive_rate: f64) -> Self {
+        let (num_counters, num_hashes) = optimal_parameters(items, false_positive_rate);
+        CountingBloomFilter::new(key, num_counters as usize, num_hashes)
+    }
+
+    /// The key of the filter
+    #[must_use]
+    pub fn key(&self) -> Key {
+        self.family.key()
+    }
+
+    /// The number of counters in the filter
+    #[must_use]
+    pub fn num_counters(&self) -> usize {
+        self.counters.len()
+    }
+
+    /// The number of counters incremented per item
+    #[must_use]
+    pub fn num_hashes(&self) -> u32 {
+        self.num_hashes
+    }
+
+    fn indices(&self, item: &[u8]) -> Indices {
+        self.family
+            .indices(item, self.num_hashes as usize, self.counters.len() as u64)
+    }
+
+    /// Adds an item to the filter
+    pub fn insert(&mut self, item: &[u8]) {
+        for index in self.indices(item) {
+            let counter = &mut self.counters[index as usize];
+            *counter = counter.saturating_add(1);
+        }
+    }
+
+    /// Removes an item from the filter. Returns false, leaving the filter untouched, if the item
+    /// was definitely not present.
+    ///
+    /// Removing an item that was never inserted but tests as present will cause false
+    /// negatives for other items.
+    pub fn remove(&mut self, item: &[u8]) -> bool {
+        if !self.contains(item) {
+            return false;
+        }
+
+        for index in self.indices(item) {
+            let counter = &mut self.counters[index as usize];
+            if *counter != u8::MAX {
+                *counter -= 1;
+            }
+        }
+        true
+    }
+
+    /// Returns false if the item is not in the filter, and true if it probably is
+    #[must_use]
+    pub fn contains(&self, item: &[u8]) -> bool {
+        self.count(item) != 0
+    }
+
+    /// Returns an upper bound on the number of times the item was inserted
+    #[must_use]
+    pub fn count(&self, item: &[u8]) -> u8 {
+        self.indices(item)
+            .map(|index| self.counters[index as usize])
+            .min()
+            .unwrap_or(0)
+    }
+
+    /// Removes all items from the filter
+    pub fn clear(&mut self) {
+        self.counters.iter_mut().for_each(|counter| *counter = 0);
+    }
+
+    /// Serializes the filter. The key is not included and must be passed to
+    /// [`CountingBloomFilter::from_bytes`] separately.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = write_header(
+            Self::MAGIC,
+            self.counters.len() as u64,
+            self.num_hashes,
+            self.counters.len(),
+        );
+        out.extend_from_slice(&self.counters);
+        out
+    }
+
+    /// Deserializes a filter written by [`CountingBloomFilter::to_bytes`] that was keyed with
+    /// `key`
+    ///
+    /// A different key is not detected, the filter just won't recognize the inserted items.
+    pub fn from_bytes(key: Key, data: &[u8]) -> Result<Self, FromBytesError> {
+        let (num_counters, num_hashes, table) = read_header(Self::MAGIC, data)?;
+        if table.len() as u64 != num_counters {
+            return Err(FromBytesError(()));
+        }
+
+        Ok(CountingBloomFilter {
+            family: HashFamily::new(key),
+            counters: table.to_vec(),
+            num_hashes,
+        })
+    }
+}
@@ -1,41 +1,47 @@
 #![allow(unsafe_code)]
 
 use crate::key::Key;
-use crate::traits::HighwayHash;
-use core::{default::Default, fmt::Debug, mem::ManuallyDrop};
+use crate::traits::{HighwayHash, HighwayHashReset, ResetError};
+use core::{default::Default, fmt::Debug, mem::ManuallyDrop, ops::Deref};
 
 #[cfg(target_arch = "aarch64")]
 use crate::aarch64::NeonHash;
-#[cfg(not(any(
-    all(target_family = "wasm", target_feature = "simd128"),
-    target_arch = "aarch64"
-)))]
 use crate::portable::PortableHash;
+#[cfg(target_pointer_width = "32")]
+use crate::portable32::Portable32Hash;
+#[cfg(feature = "nightly-simd")]
+use crate::simd::SimdHash;
 #[cfg(all(target_family = "wasm", target_feature = "simd128"))]
 use crate::wasm::WasmHash;
-#[cfg(target_arch = "x86_64")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 use crate::{AvxHash, SseHash};
 
 /// This union is purely for performance. Originally it was an enum, but Rust /
 /// LLVM had a hard time optimizing it and would include memcpy's that would
 /// dominate profiles.
+#[derive(Clone, Copy)]
 union HighwayChoices {
     #[cfg(not(any(
         all(target_family = "wasm", target_feature = "simd128"),
         target_arch = "aarch64"
     )))]
     portable: ManuallyDrop<PortableHash>,
-    #[cfg(target_arch = "x86_64")]
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     avx: ManuallyDrop<AvxHash>,
-    #[cfg(target_arch = "x86_64")]
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     sse: ManuallyDrop<SseHash>,
     #[cfg(target_arch = "aarch64")]
     neon: ManuallyDrop<NeonHash>,
     #[cfg(all(target_family = "wasm", target_feature = "simd128"))]
     wasm: ManuallyDrop<WasmHash>,
+    #[cfg(feature = "nightly-simd")]
+    simd: ManuallyDrop<SimdHash>,
+    #[cfg(target_pointer_width = "32")]
+    portable32: ManuallyDrop<Portable32Hash>,
 }
 
 /// `HighwayHash` implementation that selects best hash implementation at runtime.
+#[derive(Clone, Copy)]
 pub struct HighwayHasher {
     tag: u8,
     inner: HighwayChoices,
@@ -52,14 +58,18 @@ impl Debug for HighwayHasher {
                 target_arch = "aarch64"
             )))]
             0 => debug.field("hasher", unsafe { &self.inner.portable }),
-            #[cfg(target_arch = "x86_64")]
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
             1 => debug.field("hasher", unsafe { &self.inner.avx }),
-            #[cfg(target_arch = "x86_64")]
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
             2 => debug.field("hasher", unsafe { &self.inner.sse }),
             #[cfg(target_arch = "aarch64")]
             3 => debug.field("hasher", unsafe { &self.inner.neon }),
             #[cfg(all(target_family = "wasm", target_feature = "simd128"))]
             4 => debug.field("hasher", unsafe { &self.inner.wasm }),
+            #[cfg(feature = "nightly-simd")]
+            5 => debug.field("hasher", unsafe { &self.inner.simd }),
+            #[cfg(target_pointer_width = "32")]
+            6 => debug.field("hasher", unsafe { &self.inner.portable32 }),
             _ => unsafe { core::hint::unreachable_unchecked() },
         };
 
@@ -67,59 +77,17 @@ impl Debug for HighwayHasher {
     }
 }
 
-impl Clone for HighwayHasher {
-    fn clone(&self) -> Self {
-        let tag = self.tag;
-        match tag {
-            #[cfg(not(any(
-                all(target_family = "wasm", target_feature = "simd128"),
-                target_arch = "aarch64"
-            )))]
-            0 => HighwayHasher {
-                tag,
-                inner: HighwayChoices {
-                    portable: unsafe { self.inner.portable.clone() },
-                },
-            },
-            #[cfg(target_arch = "x86_64")]
-            1 => HighwayHasher {
-                tag,
-                inner: HighwayChoices {
-                    avx: unsafe { self.inner.avx.clone() },
-                },
-            },
-            #[cfg(target_arch = "x86_64")]
-            2 => HighwayHasher {
-                tag,
-                inner: HighwayChoices {
-                    sse: unsafe { self.inner.sse.clone() },
-                },
-            },
-            #[cfg(target_arch = "aarch64")]
-            3 => HighwayHasher {
-                tag,
-                inner: HighwayChoices {
-                    neon: unsafe { self.inner.neon.clone() },
-                },
-            },
-            #[cfg(all(target_family = "wasm", target_feature = "simd128"))]
-            4 => HighwayHasher {
-                tag,
-                inner: HighwayChoices {
-                    wasm: unsafe { self.inner.wasm.clone() },
-                },
-            },
-            _ => unsafe { core::hint::unreachable_unchecked() },
-        }
-    }
-}
-
 impl HighwayHash for HighwayHasher {
     #[inline]
     fn append(&mut self, data: &[u8]) {
         self.append(data);
     }
 
+    #[inline]
+    fn append_vectored<T: Deref<Target = [u8]>>(&mut self, fragments: &[T]) {
+        self.append_vectored(fragments);
+    }
+
     #[inline]
     fn finalize64(mut self) -> u64 {
         Self::finalize64(&mut self)
@@ -141,11 +109,55 @@ impl HighwayHash for HighwayHasher {
     }
 }
 
+impl HighwayHashReset for HighwayHasher {
+    #[inline]
+    fn finalize64_reset(&mut self) -> Result<u64, ResetError> {
+        let key = self.key().ok_or(ResetError(()))?;
+        let hash = Self::finalize64(self);
+        Self::rekey(self, key);
+        Ok(hash)
+    }
+
+    #[inline]
+    fn finalize128_reset(&mut self) -> Result<[u64; 2], ResetError> {
+        let key = self.key().ok_or(ResetError(()))?;
+        let hash = Self::finalize128(self);
+        Self::rekey(self, key);
+        Ok(hash)
+    }
+
+    #[inline]
+    fn finalize256_reset(&mut self) -> Result<[u64; 4], ResetError> {
+        let key = self.key().ok_or(ResetError(()))?;
+        let hash = Self::finalize256(self);
+        Self::rekey(self, key);
+        Ok(hash)
+    }
+
+    #[inline]
+    fn reset(&mut self) -> Result<(), ResetError> {
+        let key = self.key().ok_or(ResetError(()))?;
+        Self::rekey(self, key);
+        Ok(())
+    }
+
+    #[inline]
+    fn rekey(&mut self, key: Key) {
+        Self::rekey(self, key);
+    }
+}
+
 impl HighwayHasher {
+    /// Creates a new hasher from a key whose initial state has already been computed
+    #[must_use]
+    pub fn from_prepared(key: &PreparedKey) -> Self {
+        key.hasher()
+    }
+
     /// Creates a new hasher based on compilation and runtime capabilities
     #[must_use]
     pub fn new(key: Key) -> Self {
-        #[cfg(target_arch = "x86_64")]
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
         {
             if cfg!(target_feature = "avx2") {
                 let avx = ManuallyDrop::new(unsafe { AvxHash::force_new(key) });
@@ -205,10 +217,45 @@ impl HighwayHasher {
             }
         }
 
-        #[cfg(not(any(
-            all(target_family = "wasm", target_feature = "simd128"),
-            target_arch = "aarch64"
-        )))]
+        #[cfg(all(
+            not(any(
+                all(target_family = "wasm", target_feature = "simd128"),
+                target_arch = "aarch64"
+            )),
+            feature = "nightly-simd"
+        ))]
+        {
+            let simd = ManuallyDrop::new(SimdHash::new(key));
+            HighwayHasher {
+                tag: 5,
+                inner: HighwayChoices { simd },
+            }
+        }
+
+        #[cfg(all(
+            not(any(
+                all(target_family = "wasm", target_feature = "simd128"),
+                target_arch = "aarch64"
+            )),
+            not(feature = "nightly-simd"),
+            target_pointer_width = "32"
+        ))]
+        {
+            let portable32 = ManuallyDrop::new(Portable32Hash::new(key));
+            HighwayHasher {
+                tag: 6,
+                inner: HighwayChoices { portable32 },
+            }
+        }
+
+        #[cfg(all(
+            not(any(
+                all(target_family = "wasm", target_feature = "simd128"),
+                target_arch = "aarch64"
+            )),
+            not(feature = "nightly-simd"),
+            not(target_pointer_width = "32")
+        ))]
         {
             let portable = ManuallyDrop::new(PortableHash::new(key));
             HighwayHasher {
@@ -221,16 +268,29 @@ impl HighwayHasher {
     /// Creates a new hasher based on compilation and runtime capabilities
     #[must_use]
     pub fn from_checkpoint(data: [u8; 164]) -> Self {
-        #[cfg(target_arch = "x86_64")]
+        Self::from_portable(PortableHash::from_checkpoint(data))
+    }
+
+    /// Creates a new hasher from a checkpoint and the key it was created with, based on
+    /// compilation and runtime capabilities. A checkpoint does not record the key, so supplying
+    /// it here lets [`HighwayHashReset::reset`] restore the keyed initial state.
+    #[must_use]
+    pub fn from_checkpoint_with_key(data: [u8; 164], key: Key) -> Self {
+        Self::from_portable(PortableHash::from_checkpoint_with_key(data, key))
+    }
+
+    /// Moves a portable state into the hasher selected by compilation and runtime capabilities
+    pub(crate) fn from_portable(portable: PortableHash) -> Self {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
         {
             if cfg!(target_feature = "avx2") {
-                let avx = ManuallyDrop::new(unsafe { AvxHash::force_from_checkpoint(data) });
+                let avx = ManuallyDrop::new(unsafe { AvxHash::force_from_portable(&portable) });
                 return HighwayHasher {
                     tag: 1,
                     inner: HighwayChoices { avx },
                 };
             } else if cfg!(target_feature = "sse4.1") {
-                let sse = ManuallyDrop::new(unsafe { SseHash::force_from_checkpoint(data) });
+                let sse = ManuallyDrop::new(unsafe { SseHash::force_from_portable(&portable) });
                 return HighwayHasher {
                     tag: 2,
                     inner: HighwayChoices { sse },
@@ -240,7 +300,7 @@ impl HighwayHasher {
                 // duplicate the same logic to know if hasher can be enabled.
                 #[cfg(feature = "std")]
                 if is_x86_feature_detected!("avx2") {
-                    let avx = ManuallyDrop::new(unsafe { AvxHash::force_from_checkpoint(data) });
+                    let avx = ManuallyDrop::new(unsafe { AvxHash::force_from_portable(&portable) });
                     return HighwayHasher {
                         tag: 1,
                         inner: HighwayChoices { avx },
@@ -249,7 +309,7 @@ impl HighwayHasher {
 
                 #[cfg(feature = "std")]
                 if is_x86_feature_detected!("sse4.1") {
-                    let sse = ManuallyDrop::new(unsafe { SseHash::force_from_checkpoint(data) });
+                    let sse = ManuallyDrop::new(unsafe { SseHash::force_from_portable(&portable) });
                     return HighwayHasher {
                         tag: 2,
                         inner: HighwayChoices { sse },
@@ -265,7 +325,7 @@ impl HighwayHasher {
             //
             // It seems reasonable to assume the aarch64 is neon capable.
             // If a case is found where that is not true, we can patch later.
-            let neon = ManuallyDrop::new(unsafe { NeonHash::force_from_checkpoint(data) });
+            let neon = ManuallyDrop::new(unsafe { NeonHash::force_from_portable(&portable) });
             HighwayHasher {
                 tag: 3,
                 inner: HighwayChoices { neon },
@@ -274,19 +334,54 @@ impl HighwayHasher {
 
         #[cfg(all(target_family = "wasm", target_feature = "simd128"))]
         {
-            let wasm = ManuallyDrop::new(WasmHash::from_checkpoint(data));
+            let wasm = ManuallyDrop::new(WasmHash::from_portable(&portable));
             HighwayHasher {
                 tag: 4,
                 inner: HighwayChoices { wasm },
             }
         }
 
-        #[cfg(not(any(
-            all(target_family = "wasm", target_feature = "simd128"),
-            target_arch = "aarch64"
-        )))]
+        #[cfg(all(
+            not(any(
+                all(target_family = "wasm", target_feature = "simd128"),
+                target_arch = "aarch64"
+            )),
+            feature = "nightly-simd"
+        ))]
+        {
+            let simd = ManuallyDrop::new(SimdHash::from_portable(&portable));
+            HighwayHasher {
+                tag: 5,
+                inner: HighwayChoices { simd },
+            }
+        }
+
+        #[cfg(all(
+            not(any(
+                all(target_family = "wasm", target_feature = "simd128"),
+                target_arch = "aarch64"
+            )),
+            not(feature = "nightly-simd"),
+            target_pointer_width = "32"
+        ))]
         {
-            let portable = ManuallyDrop::new(PortableHash::from_checkpoint(data));
+            let portable32 = ManuallyDrop::new(Portable32Hash::from_portable(&portable));
+            HighwayHasher {
+                tag: 6,
+                inner: HighwayChoices { portable32 },
+            }
+        }
+
+        #[cfg(all(
+            not(any(
+                all(target_family = "wasm", target_feature = "simd128"),
+                target_arch = "aarch64"
+            )),
+            not(feature = "nightly-simd"),
+            not(target_pointer_width = "32")
+        ))]
+        {
+            let portable = ManuallyDrop::new(portable);
             HighwayHasher {
                 tag: 0,
                 inner: HighwayChoices { portable },
@@ -301,14 +396,112 @@ impl HighwayHasher {
                 target_arch = "aarch64"
             )))]
             0 => unsafe { &mut self.inner.portable }.append(data),
-            #[cfg(target_arch = "x86_64")]
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
             1 => unsafe { &mut self.inner.avx }.append(data),
-            #[cfg(target_arch = "x86_64")]
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
             2 => unsafe { &mut self.inner.sse }.append(data),
             #[cfg(target_arch = "aarch64")]
             3 => unsafe { &mut self.inner.neon }.append(data),
             #[cfg(all(target_family = "wasm", target_feature = "simd128"))]
             4 => unsafe { &mut self.inner.wasm }.append(data),
+            #[cfg(feature = "nightly-simd")]
+            5 => unsafe { &mut self.inner.simd }.append(data),
+            #[cfg(target_pointer_width = "32")]
+            6 => unsafe { &mut self.inner.portable32 }.append(data),
+            _ => unsafe { core::hint::unreachable_unchecked() },
+        }
+    }
+
+    fn append_vectored<T: Deref<Target = [u8]>>(&mut self, fragments: &[T]) {
+        match self.tag {
+            #[cfg(not(any(
+                all(target_family = "wasm", target_feature = "simd128"),
+                target_arch = "aarch64"
+            )))]
+            0 => unsafe { &mut self.inner.portable }.append_vectored(fragments),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            1 => unsafe { &mut self.inner.avx }.append_vectored(fragments),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            2 => unsafe { &mut self.inner.sse }.append_vectored(fragments),
+            #[cfg(target_arch = "aarch64")]
+            3 => unsafe { &mut self.inner.neon }.append_vectored(fragments),
+            #[cfg(all(target_family = "wasm", target_feature = "simd128"))]
+            4 => unsafe { &mut self.inner.wasm }.append_vectored(fragments),
+            #[cfg(feature = "nightly-simd")]
+            5 => unsafe { &mut self.inner.simd }.append_vectored(fragments),
+            #[cfg(target_pointer_width = "32")]
+            6 => unsafe { &mut self.inner.portable32 }.append_vectored(fragments),
+            _ => unsafe { core::hint::unreachable_unchecked() },
+        }
+    }
+
+    #[inline]
+    fn append_array<const N: usize>(&mut self, data: [u8; N]) {
+        match self.tag {
+            #[cfg(not(any(
+                all(target_family = "wasm", target_feature = "simd128"),
+                target_arch = "aarch64"
+            )))]
+            0 => unsafe { &mut self.inner.portable }.append_array(data),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            1 => unsafe { &mut self.inner.avx }.append_array(data),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            2 => unsafe { &mut self.inner.sse }.append_array(data),
+            #[cfg(target_arch = "aarch64")]
+            3 => unsafe { &mut self.inner.neon }.append_array(data),
+            #[cfg(all(target_family = "wasm", target_feature = "simd128"))]
+            4 => unsafe { &mut self.inner.wasm }.append_array(data),
+            #[cfg(feature = "nightly-simd")]
+            5 => unsafe { &mut self.inner.simd }.append_array(data),
+            #[cfg(target_pointer_width = "32")]
+            6 => unsafe { &mut self.inner.portable32 }.append_array(data),
+            _ => unsafe { core::hint::unreachable_unchecked() },
+        }
+    }
+
+    /// The key the active hasher was created with, if known
+    fn key(&self) -> Option<Key> {
+        match self.tag {
+            #[cfg(not(any(
+                all(target_family = "wasm", target_feature = "simd128"),
+                target_arch = "aarch64"
+            )))]
+            0 => unsafe { self.inner.portable.key },
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            1 => unsafe { self.inner.avx.key },
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            2 => unsafe { self.inner.sse.key },
+            #[cfg(target_arch = "aarch64")]
+            3 => unsafe { self.inner.neon.key },
+            #[cfg(all(target_family = "wasm", target_feature = "simd128"))]
+            4 => unsafe { self.inner.wasm.key },
+            #[cfg(feature = "nightly-simd")]
+            5 => unsafe { self.inner.simd.key },
+            #[cfg(target_pointer_width = "32")]
+            6 => unsafe { self.inner.portable32.key },
+            _ => unsafe { core::hint::unreachable_unchecked() },
+        }
+    }
+
+    fn rekey(&mut self, key: Key) {
+        match self.tag {
+            #[cfg(not(any(
+                all(target_family = "wasm", target_feature = "simd128"),
+                target_arch = "aarch64"
+            )))]
+            0 => HighwayHashReset::rekey(unsafe { &mut *self.inner.portable }, key),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            1 => HighwayHashReset::rekey(unsafe { &mut *self.inner.avx }, key),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            2 => HighwayHashReset::rekey(unsafe { &mut *self.inner.sse }, key),
+            #[cfg(target_arch = "aarch64")]
+            3 => HighwayHashReset::rekey(unsafe { &mut *self.inner.neon }, key),
+            #[cfg(all(target_family = "wasm", target_feature = "simd128"))]
+            4 => HighwayHashReset::rekey(unsafe { &mut *self.inner.wasm }, key),
+            #[cfg(feature = "nightly-simd")]
+            5 => HighwayHashReset::rekey(unsafe { &mut *self.inner.simd }, key),
+            #[cfg(target_pointer_width = "32")]
+            6 => HighwayHashReset::rekey(unsafe { &mut *self.inner.portable32 }, key),
             _ => unsafe { core::hint::unreachable_unchecked() },
         }
     }
@@ -320,14 +513,18 @@ impl HighwayHasher {
                 target_arch = "aarch64"
             )))]
             0 => unsafe { PortableHash::finalize64(&mut self.inner.portable) },
-            #[cfg(target_arch = "x86_64")]
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
             1 => unsafe { AvxHash::finalize64(&mut self.inner.avx) },
-            #[cfg(target_arch = "x86_64")]
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
             2 => unsafe { SseHash::finalize64(&mut self.inner.sse) },
             #[cfg(target_arch = "aarch64")]
             3 => unsafe { NeonHash::finalize64(&mut self.inner.neon) },
             #[cfg(all(target_family = "wasm", target_feature = "simd128"))]
             4 => unsafe { WasmHash::finalize64(&mut self.inner.wasm) },
+            #[cfg(feature = "nightly-simd")]
+            5 => unsafe { SimdHash::finalize64(&mut self.inner.simd) },
+            #[cfg(target_pointer_width = "32")]
+            6 => unsafe { Portable32Hash::finalize64(&mut self.inner.portable32) },
             _ => unsafe { core::hint::unreachable_unchecked() },
         }
     }
@@ -339,14 +536,18 @@ impl HighwayHasher {
                 target_arch = "aarch64"
             )))]
             0 => unsafe { PortableHash::finalize128(&mut self.inner.portable) },
-            #[cfg(target_arch = "x86_64")]
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
             1 => unsafe { AvxHash::finalize128(&mut self.inner.avx) },
-            #[cfg(target_arch = "x86_64")]
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
             2 => unsafe { SseHash::finalize128(&mut self.inner.sse) },
             #[cfg(target_arch = "aarch64")]
             3 => unsafe { NeonHash::finalize128(&mut self.inner.neon) },
             #[cfg(all(target_family = "wasm", target_feature = "simd128"))]
             4 => unsafe { WasmHash::finalize128(&mut self.inner.wasm) },
+            #[cfg(feature = "nightly-simd")]
+            5 => unsafe { SimdHash::finalize128(&mut self.inner.simd) },
+            #[cfg(target_pointer_width = "32")]
+            6 => unsafe { Portable32Hash::finalize128(&mut self.inner.portable32) },
             _ => unsafe { core::hint::unreachable_unchecked() },
         }
     }
@@ -358,14 +559,18 @@ impl HighwayHasher {
                 target_arch = "aarch64"
             )))]
             0 => unsafe { PortableHash::finalize256(&mut self.inner.portable) },
-            #[cfg(target_arch = "x86_64")]
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
             1 => unsafe { AvxHash::finalize256(&mut self.inner.avx) },
-            #[cfg(target_arch = "x86_64")]
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
             2 => unsafe { SseHash::finalize256(&mut self.inner.sse) },
             #[cfg(target_arch = "aarch64")]
             3 => unsafe { NeonHash::finalize256(&mut self.inner.neon) },
             #[cfg(all(target_family = "wasm", target_feature = "simd128"))]
             4 => unsafe { WasmHash::finalize256(&mut self.inner.wasm) },
+            #[cfg(feature = "nightly-simd")]
+            5 => unsafe { SimdHash::finalize256(&mut self.inner.simd) },
+            #[cfg(target_pointer_width = "32")]
+            6 => unsafe { Portable32Hash::finalize256(&mut self.inner.portable32) },
             _ => unsafe { core::hint::unreachable_unchecked() },
         }
     }
@@ -377,14 +582,18 @@ impl HighwayHasher {
                 target_arch = "aarch64"
             )))]
             0 => unsafe { PortableHash::checkpoint(&self.inner.portable) },
-            #[cfg(target_arch = "x86_64")]
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
             1 => unsafe { AvxHash::checkpoint(&self.inner.avx) },
-            #[cfg(target_arch = "x86_64")]
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
             2 => unsafe { SseHash::checkpoint(&self.inner.sse) },
             #[cfg(target_arch = "aarch64")]
             3 => unsafe { NeonHash::checkpoint(&self.inner.neon) },
             #[cfg(all(target_family = "wasm", target_feature = "simd128"))]
             4 => unsafe { WasmHash::checkpoint(&self.inner.wasm) },
+            #[cfg(feature = "nightly-simd")]
+            5 => unsafe { SimdHash::checkpoint(&self.inner.simd) },
+            #[cfg(target_pointer_width = "32")]
+            6 => unsafe { Portable32Hash::checkpoint(&self.inner.portable32) },
             _ => unsafe { core::hint::unreachable_unchecked() },
         }
     }
@@ -396,8 +605,112 @@ impl Default for HighwayHasher {
     }
 }
 
+/// A key with the initial hasher state precomputed.
+///
+/// Constructing a hasher from a key involves detecting CPU features and mixing the key into the
+/// initial state. When the same key is used repeatedly (eg: hashing many small messages, or in
+/// a `HashMap`), preparing the key once and then cloning the prepared state avoids redoing that
+/// work for every hasher.
+///
+/// ```rust
+/// use highway::{HighwayHash, HighwayHasher, Key, PreparedKey};
+/// let key = Key([1, 2, 3, 4]);
+/// let prepared = PreparedKey::new(key);
+/// assert_eq!(prepared.hash64(b"hello"), HighwayHasher::new(key).hash64(b"hello"));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct PreparedKey {
+    key: Key,
+    hasher: HighwayHasher,
+}
+
+impl PreparedKey {
+    /// Prepares the initial hasher state for a given key
+    #[must_use]
+    pub fn new(key: Key) -> Self {
+        PreparedKey {
+            key,
+            hasher: HighwayHasher::new(key),
+        }
+    }
+
+    /// The key that was prepared
+    #[must_use]
+    pub fn key(&self) -> Key {
+        self.key
+    }
+
+    /// Creates a new hasher from the prepared state
+    #[must_use]
+    pub fn hasher(&self) -> HighwayHasher {
+        self.hasher
+    }
+
+    /// Hashes data in a single call and returns a 64bit hash
+    #[must_use]
+    pub fn hash64(&self, data: &[u8]) -> u64 {
+        self.hasher().hash64(data)
+    }
+
+    /// Hashes data in a single call and returns a 128bit hash
+    #[must_use]
+    pub fn hash128(&self, data: &[u8]) -> [u64; 2] {
+        self.hasher().hash128(data)
+    }
+
+    /// Hashes data in a single call and returns a 256bit hash
+    #[must_use]
+    pub fn hash256(&self, data: &[u8]) -> [u64; 4] {
+        self.hasher().hash256(data)
+    }
+}
+
+impl Default for PreparedKey {
+    fn default() -> Self {
+        PreparedKey::new(Key::default())
+    }
+}
+
+impl From<Key> for PreparedKey {
+    fn from(key: Key) -> Self {
+        PreparedKey::new(key)
+    }
+}
+
 impl_write!(HighwayHasher);
-impl_hasher!(HighwayHasher);
+
+impl core::hash::Hasher for HighwayHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        self.append(bytes);
+    }
+
+    impl_hasher!(@write_ints);
+
+    fn finish(&self) -> u64 {
+        // Same reasoning as the other hashers for cloning, except only the active hasher is
+        // cloned and finalized so that short keys avoid dispatching on the tag twice.
+        match self.tag {
+            #[cfg(not(any(
+                all(target_family = "wasm", target_feature = "simd128"),
+                target_arch = "aarch64"
+            )))]
+            0 => unsafe { PortableHash::clone(&self.inner.portable).finalize64() },
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            1 => unsafe { AvxHash::clone(&self.inner.avx).finalize64() },
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            2 => unsafe { SseHash::clone(&self.inner.sse).finalize64() },
+            #[cfg(target_arch = "aarch64")]
+            3 => unsafe { NeonHash::clone(&self.inner.neon).finalize64() },
+            #[cfg(all(target_family = "wasm", target_feature = "simd128"))]
+            4 => unsafe { WasmHash::clone(&self.inner.wasm).finalize64() },
+            #[cfg(feature = "nightly-simd")]
+            5 => unsafe { SimdHash::clone(&self.inner.simd).finalize64() },
+            #[cfg(target_pointer_width = "32")]
+            6 => unsafe { Portable32Hash::clone(&self.inner.portable32).finalize64() },
+            _ => unsafe { core::hint::unreachable_unchecked() },
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -409,4 +722,28 @@ mod tests {
         let output = format!("{:?}", &hasher);
         assert!(output.contains("hasher: "));
     }
+
+    #[test]
+    fn test_prepared_key_hashes_match() {
+        let key = Key([1, 2, 3, 4]);
+        let prepared = PreparedKey::new(key);
+        let data = [0u8; 100];
+        for i in 0..data.len() {
+            let data = &data[..i];
+            assert_eq!(prepared.hash64(data), HighwayHasher::new(key).hash64(data));
+            assert_eq!(
+                prepared.hash128(data),
+                HighwayHasher::new(key).hash128(data)
+            );
+            assert_eq!(
+                prepared.hash256(data),
+                HighwayHasher::new(key).hash256(data)
+            );
+        }
+
+        let mut hasher = HighwayHasher::from_prepared(&prepared);
+        hasher.append(&data);
+        let expected = HighwayHasher::new(key).hash256(&data);
+        assert_eq!(hasher.finalize256(), expected);
+    }
 }
@@ -0,0 +1,76 @@
+//! Collection aliases keyed with [`HighwayBuildHasher`] for the `hashbrown` and `indexmap`
+//! crates. Neither requires std, so these are available to `no_std` + `alloc` code.
+//!
+//! ```rust
+//! use highway::collections::{HighwayCollection, HighwayHashMap};
+//! use highway::Key;
+//!
+//! let mut map: HighwayHashMap<&str, u32> = HighwayHashMap::with_key(Key::new([1, 2, 3, 4]));
+//! map.insert("a", 1);
+//! assert_eq!(map.get("a"), Some(&1));
+//! ```
+//!
+//! Keys from a random number generator can be used via [`Key::from_bytes`].
+
+use crate::hash::HighwayBuildHasher;
+use crate::key::Key;
+
+/// A `hashbrown::HashMap` hashed with HighwayHash
+#[cfg(feature = "hashbrown")]
+pub type HighwayHashMap<K, V> = hashbrown::HashMap<K, V, HighwayBuildHasher>;
+
+/// A `hashbrown::HashSet` hashed with HighwayHash
+#[cfg(feature = "hashbrown")]
+pub type HighwayHashSet<T> = hashbrown::HashSet<T, HighwayBuildHasher>;
+
+/// An `indexmap::IndexMap` hashed with HighwayHash
+#[cfg(feature = "indexmap")]
+pub type HighwayIndexMap<K, V> = indexmap::IndexMap<K, V, HighwayBuildHasher>;
+
+/// An `indexmap::IndexSet` hashed with HighwayHash
+#[cfg(feature = "indexmap")]
+pub type HighwayIndexSet<T> = indexmap::IndexSet<T, HighwayBuildHasher>;
+
+/// Constructors for collections hashed with a [`HighwayBuildHasher`]
+pub trait HighwayCollection: Sized {
+    /// Creates an empty collection that can hold at least `capacity` elements before
+    /// reallocating
+    fn with_capacity_and_build_hasher(capacity: usize, hasher: HighwayBuildHasher) -> Self;
+
+    /// Creates an empty collection hashed with the given key
+    fn with_key(key: Key) -> Self {
+        Self::with_capacity_and_key(0, key)
+    }
+
+    /// Creates an empty collection with the given capacity, hashed with the given key
+    fn with_capacity_and_key(capacity: usize, key: Key) -> Self {
+        Self::with_capacity_and_build_hasher(capacity, HighwayBuildHasher::new(key))
+    }
+
+    /// Creates an empty collection hashed with a key filled from a random source (eg:
+    /// `getrandom::fill`)
+    fn with_random_key<F>(fill: F) -> Self
+    where
+        F: FnOnce(&mut [u8; 32]),
+    {
+        let mut bytes = [0u8; 32];
+        fill(&mut bytes);
+        Self::with_key(Key::from_bytes(bytes))
+    }
+}
+
+macro_rules! impl_collection {
+    ($feature:literal, $collection:ident < $($param:ident),+ >) => {
+        #[cfg(feature = $feature)]
+        impl<$($param),+> HighwayCollection for $collection<$($param),+> {
+            fn with_capacity_and_build_hasher(capacity: usize, hasher: HighwayBuildHasher) -> Self {
+                $collection::with_capacity_and_hasher(capacity, hasher)
+            }
+        }
+    };
+}
+
+impl_collection!("hashbrown", HighwayHashMap<K, V>);
+impl_collection!("hashbrown", HighwayHashSet<T>);
+impl_collection!("indexmap", HighwayIndexMap<K, V>);
+impl_collection!("indexmap", HighwayIndexSet<T>);
@@ -0,0 +1,64 @@
+//! Hashing in `const` contexts, so hashes of literals can be computed at compile time. The const
+//! hashers run the same `const fn` algorithm as `PortableHash`, copying each packet out of the
+//! message as slicing isn't available in const functions.
+
+use crate::internal::PACKET_SIZE;
+use crate::key::Key;
+use crate::portable::{PortableHash, State};
+
+const fn absorb(key: &Key, data: &[u8]) -> State {
+    let mut state = State::new(key);
+    let mut packet = [0u8; PACKET_SIZE];
+    let mut offset = 0;
+    while offset + PACKET_SIZE <= data.len() {
+        let mut i = 0;
+        while i < PACKET_SIZE {
+            packet[i] = data[offset + i];
+            i += 1;
+        }
+
+        state = state.update(PortableHash::data_to_lanes(&packet));
+        offset += PACKET_SIZE;
+    }
+
+    let len = data.len() - offset;
+    if len > 0 {
+        let mut i = 0;
+        while i < len {
+            packet[i] = data[offset + i];
+            i += 1;
+        }
+
+        state = state.update_remainder(&packet, len);
+    }
+
+    state
+}
+
+/// Computes the 64-bit hash of `data` in a `const` context. The result is identical to
+/// `HighwayHasher::new(key).hash64(data)`.
+///
+/// ```rust
+/// use highway::{hash64_const, HighwayHash, HighwayHasher, Key};
+/// const KEY: Key = Key::new([1, 2, 3, 4]);
+/// const MESSAGE_ID: u64 = hash64_const(KEY, b"user.created");
+/// assert_eq!(MESSAGE_ID, HighwayHasher::new(KEY).hash64(b"user.created"));
+/// ```
+#[must_use]
+pub const fn hash64_const(key: Key, data: &[u8]) -> u64 {
+    absorb(&key, data).permute_and_update(4).reduce64()
+}
+
+/// Computes the 128-bit hash of `data` in a `const` context. The result is identical to
+/// `HighwayHasher::new(key).hash128(data)`.
+#[must_use]
+pub const fn hash128_const(key: Key, data: &[u8]) -> [u64; 2] {
+    absorb(&key, data).permute_and_update(6).reduce128()
+}
+
+/// Computes the 256-bit hash of `data` in a `const` context. The result is identical to
+/// `HighwayHasher::new(key).hash256(data)`.
+#[must_use]
+pub const fn hash256_const(key: Key, data: &[u8]) -> [u64; 4] {
+    absorb(&key, data).permute_and_update(10).reduce256()
+}
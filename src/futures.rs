@@ -0,0 +1,173 @@
+//! Integration with the `futures` ecosystem.
+//!
+//! With the `futures-io` feature enabled, every hasher implements `futures_io::AsyncWrite` and
+//! [`hash_async_reader`] hashes an `AsyncRead` to completion. With the `futures-core` feature
+//! enabled, [`HashingStream`] and [`HashingTryStream`] hash the chunks of a stream as they
+//! pass through.
+
+#[cfg(feature = "futures-io")]
+use crate::async_read::{self, PollRead};
+#[cfg(feature = "futures-io")]
+use crate::Key;
+#[cfg(feature = "futures-core")]
+use crate::{HighwayHash, HighwayHasher};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// Reads until EOF and returns the 256bit hash of everything read.
+///
+/// ```rust
+/// # futures::executor::block_on(async {
+/// use highway::{HighwayHash, HighwayHasher, Key};
+/// let data = b"hello world";
+/// let hash = highway::futures::hash_async_reader(&data[..], Key::default()).await?;
+/// assert_eq!(hash, HighwayHasher::default().hash256(data));
+/// # Ok::<(), std::io::Error>(())
+/// # }).unwrap();
+/// ```
+#[cfg(feature = "futures-io")]
+pub async fn hash_async_reader<R>(reader: R, key: Key) -> std::io::Result<[u64; 4]>
+where
+    R: futures_io::AsyncRead + Unpin,
+{
+    async_read::hash_reader(FuturesRead(reader), key).await
+}
+
+#[cfg(feature = "futures-io")]
+struct FuturesRead<R>(R);
+
+#[cfg(feature = "futures-io")]
+impl<R> PollRead for FuturesRead<R>
+where
+    R: futures_io::AsyncRead + Unpin,
+{
+    fn poll_read_into(
+        &mut self,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.0).poll_read(cx, buf)
+    }
+}
+
+/// A stream adapter that hashes every chunk as it is yielded, without buffering.
+///
+/// Once the stream is exhausted, the hash of all the chunks is available with
+/// [`HashingStream::finalize256`] and friends.
+///
+/// The stream must be `Unpin`. Wrap a `!Unpin` stream with `Box::pin` before handing it over.
+///
+/// ```rust
+/// use futures::StreamExt;
+/// use highway::{HighwayHash, HighwayHasher, futures::HashingStream};
+/// # futures::executor::block_on(async {
+/// let chunks = futures::stream::iter(vec![&b"hello "[..], &b"world"[..]]);
+/// let mut stream = HashingStream::new(chunks, HighwayHasher::default());
+/// while let Some(_chunk) = stream.next().await {}
+/// assert_eq!(stream.finalize64(), HighwayHasher::default().hash64(b"hello world"));
+/// # });
+/// ```
+#[cfg(feature = "futures-core")]
+#[derive(Debug, Clone)]
+pub struct HashingStream<S, H = HighwayHasher> {
+    stream: S,
+    hasher: H,
+}
+
+/// A stream adapter like [`HashingStream`] for streams of `Result`s, such as HTTP bodies. Only
+/// the chunks of `Ok` items are hashed.
+#[cfg(feature = "futures-core")]
+#[derive(Debug, Clone)]
+pub struct HashingTryStream<S, H = HighwayHasher> {
+    stream: S,
+    hasher: H,
+}
+
+macro_rules! impl_hashing_stream {
+    ($name:ident) => {
+        #[cfg(feature = "futures-core")]
+        impl<S, H> $name<S, H>
+        where
+            H: HighwayHash,
+        {
+            /// Wraps a stream so that its chunks are appended to the given hasher
+            pub fn new(stream: S, hasher: H) -> Self {
+                $name { stream, hasher }
+            }
+
+            /// The hasher that has seen every chunk yielded so far
+            pub fn hasher(&self) -> &H {
+                &self.hasher
+            }
+
+            /// Returns the wrapped stream and the hasher
+            pub fn into_inner(self) -> (S, H) {
+                (self.stream, self.hasher)
+            }
+
+            /// Returns the 64bit hash of the chunks yielded so far
+            pub fn finalize64(self) -> u64 {
+                self.hasher.finalize64()
+            }
+
+            /// Returns the 128bit hash of the chunks yielded so far
+            pub fn finalize128(self) -> [u64; 2] {
+                self.hasher.finalize128()
+            }
+
+            /// Returns the 256bit hash of the chunks yielded so far
+            pub fn finalize256(self) -> [u64; 4] {
+                self.hasher.finalize256()
+            }
+        }
+    };
+}
+
+impl_hashing_stream!(HashingStream);
+impl_hashing_stream!(HashingTryStream);
+
+#[cfg(feature = "futures-core")]
+impl<S, H> futures_core::Stream for HashingStream<S, H>
+where
+    S: futures_core::Stream + Unpin,
+    S::Item: AsRef<[u8]>,
+    H: HighwayHash + Unpin,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let item = Pin::new(&mut this.stream).poll_next(cx);
+        if let Poll::Ready(Some(chunk)) = &item {
+            this.hasher.append(chunk.as_ref());
+        }
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+#[cfg(feature = "futures-core")]
+impl<S, H, B, E> futures_core::Stream for HashingTryStream<S, H>
+where
+    S: futures_core::Stream<Item = Result<B, E>> + Unpin,
+    B: AsRef<[u8]>,
+    H: HighwayHash + Unpin,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let item = Pin::new(&mut this.stream).poll_next(cx);
+        if let Poll::Ready(Some(Ok(chunk))) = &item {
+            this.hasher.append(chunk.as_ref());
+        }
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
@@ -1,25 +1,62 @@
-use crate::builder::HighwayHasher;
+use crate::builder::{HighwayHasher, PreparedKey};
 use crate::key::Key;
 use core::hash::BuildHasher;
 
 /// Constructs a hasher used in rust collections
-#[derive(Debug, Default)]
+///
+/// Works with std's `HashMap` as well as, through the `hashbrown` and `indexmap` features, the
+/// aliases in the `collections` module. The key is prepared once, when the builder is created,
+/// and every hasher it builds is a copy of the prepared hasher.
+#[derive(Debug, Clone, Copy)]
 pub struct HighwayBuildHasher {
-    key: Key,
+    key: PreparedKey,
 }
 
 impl HighwayBuildHasher {
     /// Creates a new hash builder with a given key
     #[must_use]
     pub fn new(key: Key) -> Self {
+        HighwayBuildHasher::from_prepared(PreparedKey::new(key))
+    }
+
+    /// Creates a new hash builder with a random key. See [`Key::random`]
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn random() -> Self {
+        HighwayBuildHasher::new(Key::random())
+    }
+
+    /// Creates a new hash builder from an already prepared key
+    #[must_use]
+    pub fn from_prepared(key: PreparedKey) -> Self {
         HighwayBuildHasher { key }
     }
+
+    /// The key given to every hasher
+    #[must_use]
+    pub fn key(&self) -> Key {
+        self.key.key()
+    }
+}
+
+impl Default for HighwayBuildHasher {
+    fn default() -> Self {
+        HighwayBuildHasher::new(Key::default())
+    }
 }
 
+impl PartialEq for HighwayBuildHasher {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for HighwayBuildHasher {}
+
 impl BuildHasher for HighwayBuildHasher {
     type Hasher = HighwayHasher;
 
     fn build_hasher(&self) -> Self::Hasher {
-        HighwayHasher::new(self.key)
+        self.key.hasher()
     }
 }
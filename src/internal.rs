@@ -1,4 +1,7 @@
+use crate::traits::HighwayHash;
+
 #[cfg(any(
+    target_arch = "x86",
     target_arch = "x86_64",
     target_arch = "aarch64",
     all(target_family = "wasm", target_feature = "simd128")
@@ -17,6 +20,46 @@ pub fn unordered_load3(from: &[u8]) -> u64 {
 
 pub const PACKET_SIZE: usize = 32;
 
+/// Appends each element of `data` as the bytes returned by `to_le`, batching them through a
+/// stack buffer so that the hash only depends on the serialization and not on the host.
+#[inline]
+pub(crate) fn append_le<H: HighwayHash, T, const N: usize>(
+    hasher: &mut H,
+    data: &[T],
+    to_le: fn(&T) -> [u8; N],
+) {
+    let mut buf = [0u8; 256];
+    for chunk in data.chunks(buf.len() / N) {
+        let mut len = 0;
+        for (x, dest) in chunk.iter().zip(buf.chunks_exact_mut(N)) {
+            dest.copy_from_slice(&to_le(x));
+            len += N;
+        }
+        hasher.append(&buf[..len]);
+    }
+}
+
+/// Appends `data` preceded by its length as a little endian `u64`, so that consecutive fields
+/// can't run into each other and different splits of the same bytes never hash alike
+#[inline]
+pub(crate) fn append_length_prefixed<H: HighwayHash>(hasher: &mut H, data: &[u8]) {
+    hasher.append_u64_le(&[data.len() as u64]);
+    hasher.append(data);
+}
+
+/// Starts a keyed hash with a label unique to its use (eg: `b"highway-token"`). Mixing the label
+/// in first means tags and hashes produced for one purpose can't be confused with those of any
+/// other use of the same key.
+#[inline]
+pub(crate) fn append_label<H: HighwayHash>(hasher: &mut H, label: &[u8]) {
+    append_length_prefixed(hasher, label);
+}
+
+/// Compares two tags without exiting early on the first differing byte
+pub(crate) fn constant_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
 /// The c layout is needed as we'll be interpretting the buffer as different types and passing it
 /// to simd instructions, so we need to subscribe to the whole "do what C does", else we will
 /// segfault.
@@ -28,6 +71,14 @@ pub struct HashPacket {
 }
 
 impl HashPacket {
+    #[inline]
+    pub const fn new() -> Self {
+        HashPacket {
+            buf: [0; PACKET_SIZE],
+            buf_index: 0,
+        }
+    }
+
     #[inline]
     pub const fn len(&self) -> usize {
         self.buf_index
@@ -64,6 +115,57 @@ impl HashPacket {
         }
     }
 
+    /// Feeds fragments through the buffer as if they were one contiguous slice, calling
+    /// `update` with each complete packet in order. Packets straddling fragment boundaries are
+    /// assembled in the buffer, packets lying within a fragment are passed in place, and only
+    /// the trailing partial packet is left buffered.
+    #[inline]
+    pub fn assemble<T, F>(&mut self, fragments: &[T], mut update: F)
+    where
+        T: core::ops::Deref<Target = [u8]>,
+        F: FnMut(&[u8]),
+    {
+        for fragment in fragments {
+            let mut data = &fragment[..];
+            if !self.is_empty() {
+                match self.fill(data) {
+                    Some(tail) => {
+                        update(&self.buf);
+                        self.buf_index = 0;
+                        data = tail;
+                    }
+                    None => continue,
+                }
+            }
+
+            let mut chunks = data.chunks_exact(PACKET_SIZE);
+            for chunk in chunks.by_ref() {
+                update(chunk);
+            }
+            self.set_to(chunks.remainder());
+        }
+    }
+
+    /// Copies a fixed size array into the buffer if it fits without completing the packet.
+    /// Returns false, leaving the buffer untouched, when the caller needs to fall back to
+    /// appending the bytes as a slice.
+    #[inline]
+    pub fn try_push<const N: usize>(&mut self, data: [u8; N]) -> bool {
+        let end = self.buf_index + N;
+        if end >= PACKET_SIZE {
+            return false;
+        }
+
+        match self.buf.get_mut(self.buf_index..end) {
+            Some(dest) => {
+                dest.copy_from_slice(&data);
+                self.buf_index = end;
+                true
+            }
+            None => false,
+        }
+    }
+
     #[inline]
     pub fn set_to(&mut self, data: &[u8]) {
         debug_assert!(
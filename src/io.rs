@@ -0,0 +1,342 @@
+//! Adapters that hash data as it flows through `std::io` readers and writers, and helpers for
+//! hashing whole files.
+
+use crate::internal::PACKET_SIZE;
+use crate::{HighwayHash, HighwayHasher, Key};
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+/// Size of the buffer used when reading. Kept a multiple of the packet size so that every
+/// full buffer is handed to the hasher's bulk loop without touching its internal buffer.
+const READ_BUFFER_SIZE: usize = 2048 * PACKET_SIZE;
+
+/// Files at least this large are memory mapped when the `memmap2` feature is enabled
+#[cfg(feature = "memmap2")]
+const MMAP_THRESHOLD: u64 = 1024 * 1024;
+
+/// Returns the 256bit hash of a file's contents.
+///
+/// With the `memmap2` feature enabled, large regular files are memory mapped and hashed in a
+/// single call. Otherwise, or for pipes and other special files, the file is read in large
+/// chunks.
+///
+/// Memory mapping assumes that the file is not modified while it is hashed. If another process
+/// truncates or writes to the file, the hash is meaningless (and truncation may raise `SIGBUS`
+/// on unix).
+///
+/// ```rust
+/// # fn main() -> std::io::Result<()> {
+/// use highway::{HighwayHash, HighwayHasher, Key};
+/// let hash = highway::hash_file("Cargo.toml", Key::default())?;
+/// let data = std::fs::read("Cargo.toml")?;
+/// assert_eq!(hash, HighwayHasher::default().hash256(&data));
+/// # Ok(())
+/// # }
+/// ```
+pub fn hash_file<P: AsRef<Path>>(path: P, key: Key) -> io::Result<[u64; 4]> {
+    let file = File::open(path)?;
+
+    #[cfg(feature = "memmap2")]
+    {
+        let metadata = file.metadata()?;
+        if metadata.is_file() && metadata.len() >= MMAP_THRESHOLD {
+            // See the function docs regarding concurrent modification. If the file can't be
+            // mapped, fall back to reading it.
+            #[allow(unsafe_code)]
+            if let Ok(map) = unsafe { memmap2::Mmap::map(&file) } {
+                return Ok(HighwayHasher::new(key).hash256(&map));
+            }
+        }
+    }
+
+    hash_reader(file, key)
+}
+
+/// Reads until EOF and returns the 256bit hash of everything read.
+///
+/// Unlike `std::io::copy` into a hasher, the data is read into a large buffer that is only
+/// handed to the hasher once full, so short reads from pipes and sockets don't cause data to
+/// be staged in the hasher's internal buffer.
+pub fn hash_reader<R: Read>(mut reader: R, key: Key) -> io::Result<[u64; 4]> {
+    let mut hasher = HighwayHasher::new(key);
+    let mut buf = vec![0u8; READ_BUFFER_SIZE];
+    loop {
+        let mut filled = 0;
+        while filled < buf.len() {
+            match reader.read(&mut buf[filled..]) {
+                Ok(0) => break,
+                Ok(read) => filled += read,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        hasher.append(&buf[..filled]);
+        if filled < buf.len() {
+            return Ok(hasher.finalize256());
+        }
+    }
+}
+
+/// A reader that forwards reads to the inner reader and appends every byte read to a hasher.
+///
+/// ```rust
+/// use highway::{HighwayHash, HighwayHasher, io::HashingReader};
+/// use std::io::Read;
+/// let mut reader = HashingReader::new(&b"hello world"[..], HighwayHasher::default());
+/// let mut out = Vec::new();
+/// reader.read_to_end(&mut out).unwrap();
+/// let (_, hasher) = reader.into_inner();
+/// assert_eq!(hasher.finalize64(), HighwayHasher::default().hash64(b"hello world"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct HashingReader<R, H = HighwayHasher> {
+    inner: R,
+    hasher: H,
+}
+
+impl<R, H> HashingReader<R, H> {
+    /// Wraps a reader so that the data read is appended to the given hasher
+    pub fn new(inner: R, hasher: H) -> Self {
+        HashingReader { inner, hasher }
+    }
+
+    /// The wrapped reader
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// The wrapped reader. Reading from it directly bypasses the hasher.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// The hasher that has seen every byte read so far
+    pub fn hasher(&self) -> &H {
+        &self.hasher
+    }
+
+    /// Returns the wrapped reader and the hasher
+    pub fn into_inner(self) -> (R, H) {
+        (self.inner, self.hasher)
+    }
+}
+
+impl<R, H> Read for HashingReader<R, H>
+where
+    R: Read,
+    H: HighwayHash,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.hasher.append(&buf[..read]);
+        Ok(read)
+    }
+}
+
+/// A writer that forwards writes to the inner writer and appends every byte written to a
+/// hasher. Only the bytes accepted by the inner writer are hashed.
+///
+/// ```rust
+/// use highway::{HighwayHash, HighwayHasher, io::HashingWriter};
+/// use std::io::Write;
+/// let mut writer = HashingWriter::new(Vec::new(), HighwayHasher::default());
+/// writer.write_all(b"hello world").unwrap();
+/// let (out, hasher) = writer.into_inner();
+/// assert_eq!(out, b"hello world");
+/// assert_eq!(hasher.finalize64(), HighwayHasher::default().hash64(b"hello world"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct HashingWriter<W, H = HighwayHasher> {
+    inner: W,
+    hasher: H,
+}
+
+impl<W, H> HashingWriter<W, H> {
+    /// Wraps a writer so that the data written is appended to the given hasher
+    pub fn new(inner: W, hasher: H) -> Self {
+        HashingWriter { inner, hasher }
+    }
+
+    /// The wrapped writer
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// The wrapped writer. Writing to it directly bypasses the hasher.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// The hasher that has seen every byte written so far
+    pub fn hasher(&self) -> &H {
+        &self.hasher
+    }
+
+    /// Returns the wrapped writer and the hasher
+    pub fn into_inner(self) -> (W, H) {
+        (self.inner, self.hasher)
+    }
+}
+
+impl<W, H> Write for HashingWriter<W, H>
+where
+    W: Write,
+    H: HighwayHash,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.hasher.append(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// The hash a [`VerifyingReader`] expects. The width of the hash determines which finalizer
+/// is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpectedHash {
+    /// A 64bit hash
+    Hash64(u64),
+
+    /// A 128bit hash
+    Hash128([u64; 2]),
+
+    /// A 256bit hash
+    Hash256([u64; 4]),
+}
+
+impl From<u64> for ExpectedHash {
+    fn from(hash: u64) -> Self {
+        ExpectedHash::Hash64(hash)
+    }
+}
+
+impl From<[u64; 2]> for ExpectedHash {
+    fn from(hash: [u64; 2]) -> Self {
+        ExpectedHash::Hash128(hash)
+    }
+}
+
+impl From<[u64; 4]> for ExpectedHash {
+    fn from(hash: [u64; 4]) -> Self {
+        ExpectedHash::Hash256(hash)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Verification {
+    Pending,
+    Matched,
+    Mismatched,
+}
+
+/// A reader that hashes everything read and, once the inner reader reaches EOF, checks the
+/// result against an expected hash. On a mismatch the read that hit EOF, and every read after
+/// it, returns an error of kind `InvalidData`.
+///
+/// ```rust
+/// use highway::{HighwayHash, HighwayHasher, io::VerifyingReader};
+/// use std::io::Read;
+/// let expected = HighwayHasher::default().hash256(b"hello world");
+/// let mut reader = VerifyingReader::new(&b"hello world"[..], HighwayHasher::default(), expected);
+/// let mut out = Vec::new();
+/// assert!(reader.read_to_end(&mut out).is_ok());
+///
+/// let mut reader = VerifyingReader::new(&b"hello there"[..], HighwayHasher::default(), expected);
+/// assert!(reader.read_to_end(&mut out).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct VerifyingReader<R, H = HighwayHasher> {
+    inner: R,
+    /// Taken and finalized once EOF is reached
+    hasher: Option<H>,
+    expected: ExpectedHash,
+    state: Verification,
+}
+
+impl<R, H> VerifyingReader<R, H> {
+    /// Wraps a reader so that the data read must hash to the expected value
+    pub fn new(inner: R, hasher: H, expected: impl Into<ExpectedHash>) -> Self {
+        VerifyingReader {
+            inner,
+            hasher: Some(hasher),
+            expected: expected.into(),
+            state: Verification::Pending,
+        }
+    }
+
+    /// The expected hash
+    pub fn expected(&self) -> ExpectedHash {
+        self.expected
+    }
+
+    /// Returns true once EOF has been reached and the data matched the expected hash
+    pub fn is_verified(&self) -> bool {
+        self.state == Verification::Matched
+    }
+
+    /// The wrapped reader
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns the wrapped reader
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R, H> VerifyingReader<R, H>
+where
+    H: HighwayHash,
+{
+    fn verify(&mut self) -> io::Result<()> {
+        if let Some(hasher) = self.hasher.take() {
+            let matched = match self.expected {
+                ExpectedHash::Hash64(x) => hasher.finalize64() == x,
+                ExpectedHash::Hash128(x) => hasher.finalize128() == x,
+                ExpectedHash::Hash256(x) => hasher.finalize256() == x,
+            };
+
+            self.state = if matched {
+                Verification::Matched
+            } else {
+                Verification::Mismatched
+            };
+        }
+
+        match self.state {
+            Verification::Mismatched => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "data does not match the expected hash",
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<R, H> Read for VerifyingReader<R, H>
+where
+    R: Read,
+    H: HighwayHash,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.state != Verification::Pending {
+            self.verify()?;
+        }
+
+        let read = self.inner.read(buf)?;
+        if read == 0 && !buf.is_empty() {
+            self.verify()?;
+        } else if let Some(hasher) = &mut self.hasher {
+            hasher.append(&buf[..read]);
+        }
+
+        Ok(read)
+    }
+}
@@ -1,10 +1,59 @@
 use core::ops::Index;
 
 /// Key used in `HighwayHash` that will drastically change the hash outputs.
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(align(32))]
 pub struct Key(pub [u64; 4]);
 
+impl Key {
+    /// Creates a key from its four 64-bit words. Usable in `const` contexts.
+    #[must_use]
+    pub const fn new(key: [u64; 4]) -> Self {
+        Key(key)
+    }
+
+    /// Creates a key from 32 bytes (eg: the output of a random number generator), read as four
+    /// little endian words
+    #[must_use]
+    pub const fn from_bytes(bytes: [u8; 32]) -> Self {
+        let mut key = [0u64; 4];
+        let mut i = 0;
+        while i < 4 {
+            let o = i * 8;
+            key[i] = u64::from_le_bytes([
+                bytes[o],
+                bytes[o + 1],
+                bytes[o + 2],
+                bytes[o + 3],
+                bytes[o + 4],
+                bytes[o + 5],
+                bytes[o + 6],
+                bytes[o + 7],
+            ]);
+            i += 1;
+        }
+        Key(key)
+    }
+
+    /// Creates a random key. A single std `RandomState`, seeded from per-process randomness, is
+    /// created per call and each word of the key is derived from it.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn random() -> Self {
+        use std::collections::hash_map::RandomState;
+        use std::hash::{BuildHasher, Hasher};
+
+        let state = RandomState::new();
+        let mut key = [0u64; 4];
+        for (i, word) in key.iter_mut().enumerate() {
+            let mut hasher = state.build_hasher();
+            hasher.write_usize(i);
+            *word = hasher.finish();
+        }
+        Key(key)
+    }
+}
+
 impl Index<usize> for Key {
     type Output = u64;
     fn index(&self, index: usize) -> &u64 {
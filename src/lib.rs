@@ -157,37 +157,135 @@ RUSTFLAGS="-C target-feature=+sse4.1" cargo test
 RUSTFLAGS="-C target-feature=+avx2" cargo test
 ```
 
+Every hasher implements `core::fmt::Write`, so formatted output can be hashed without allocating. Enable the `embedded-io` feature for implementations of `embedded_io::Write`.
+
+## Compile-time hashing
+
+`hash64_const`, `hash128_const`, and `hash256_const` are `const fn` versions of the portable implementation, so hashes of literals (eg: message identifiers) can be computed at compile time and are guaranteed to match `HighwayHasher` at runtime.
+
+```rust
+use highway::{hash64_const, Key};
+const KEY: Key = Key::new([1, 2, 3, 4]);
+const USER_CREATED: u64 = hash64_const(KEY, b"user.created");
+```
+
+## Collections
+
+`HighwayBuildHasher` plugs into std's `HashMap`, and `HighwayBuildHasher::random` (std only) picks a per-process random key. Enabling the `hashbrown` or `indexmap` features adds `HighwayHashMap`, `HighwayHashSet`, `HighwayIndexMap`, and `HighwayIndexSet` aliases to the `highway::collections` module, which work in `no_std` + `alloc` environments. The `alloc` feature (implied by `std`) on its own enables `DynHighwayHash::box_clone`.
+
+## Probabilistic data structures
+
+The `highway::bloom` module derives any number of indices from a single 256bit hash with `HashFamily`, and (with the `alloc` feature) provides keyed, serializable `BloomFilter` and `CountingBloomFilter` types.
+
+The `highway::sketch` module adds keyed MinHash (with the `alloc` feature) and SimHash sketches for near-duplicate detection.
+
+## Sharding
+
+The `highway::shard` module provides keyed jump consistent hashing, (weighted) rendezvous hashing, a consistent hash ring with virtual nodes, and unbiased reduction of a hash into a range.
+
+The `highway::sampling` module makes deterministic, keyed sampling and A/B bucketing decisions, with documented constructions and test vectors for clients in other languages.
+
+With the `alloc` feature, `highway::pseudonym::Pseudonymizer` replaces identifiers with stable, keyed, versioned tokens for analytics and logs.
+
+The `highway::token` module (`alloc` feature) signs expiring, URL-safe tokens for session cookies and CSRF protection with a 128bit HighwayHash tag, and verifies them against the current and previous keys.
+
+The `highway::sealed` module frames messages with a 128bit HighwayHash tag over length prefixed associated data and payload, and the `tokio-util` feature adds a `SealedCodec` that seals and verifies frames on a stream.
+
+## Random numbers
+
+The `rand_core` feature adds `HighwayRng`, a seedable generator that runs HighwayHash in counter mode. Its stream is reproducible across platforms and backends and it can jump to any block, but it is not a cryptographically secure generator.
+
+## Async
+
+Enabling the `tokio` or `futures-io` features implements the respective `AsyncWrite` trait for every hasher and adds a `hash_async_reader` helper to the `highway::tokio` and `highway::futures` modules. The `futures-core` feature adds `HashingStream`, which hashes the chunks of a stream as they pass through.
+
+## Portable SIMD
+
+On targets without a dedicated SIMD implementation (eg: riscv64, loongarch64, powerpc64le), a nightly compiler can opt into `SimdHash`, which is written against `core::simd`, by enabling the `nightly-simd` feature:
+
+```bash
+cargo +nightly build --features nightly-simd
+```
+
+`HighwayHasher` will then prefer `SimdHash` over `PortableHash` whenever no native SIMD implementation is available.
+
+## 32-bit targets
+
+On 32-bit targets without SIMD (eg: wasm32 without `simd128`, armv7, riscv32), `HighwayHasher` falls back to `Portable32Hash` instead of `PortableHash`. It stores each 64-bit lane as two 32-bit halves so the hot loop only uses 32x32->64 bit multiplies, and produces identical output.
+
 */
 #![allow(non_snake_case)]
+#![cfg_attr(feature = "nightly-simd", feature(portable_simd))]
 #![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
 #![warn(missing_docs)]
 #![deny(unsafe_code)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[macro_use]
 mod macros;
+#[cfg(all(feature = "std", any(feature = "tokio", feature = "futures-io")))]
+mod async_read;
 mod builder;
+mod constant;
 mod hash;
 mod internal;
 mod key;
+mod multiset;
 mod portable;
+mod portable32;
+#[cfg(feature = "rand_core")]
+mod rng;
 mod traits;
-
-pub use crate::builder::HighwayHasher;
+mod xof;
+
+pub mod bloom;
+#[cfg(any(feature = "hashbrown", feature = "indexmap"))]
+pub mod collections;
+#[cfg(all(feature = "std", any(feature = "futures-io", feature = "futures-core")))]
+pub mod futures;
+#[cfg(feature = "std")]
+pub mod io;
+#[cfg(feature = "alloc")]
+pub mod pseudonym;
+pub mod sampling;
+pub mod sealed;
+pub mod shard;
+pub mod sketch;
+#[cfg(feature = "alloc")]
+pub mod token;
+#[cfg(all(feature = "std", feature = "tokio"))]
+pub mod tokio;
+
+pub use crate::builder::{HighwayHasher, PreparedKey};
+pub use crate::constant::{hash128_const, hash256_const, hash64_const};
 pub use crate::hash::HighwayBuildHasher;
+#[cfg(feature = "std")]
+pub use crate::io::{hash_file, hash_reader};
 pub use crate::key::Key;
+pub use crate::multiset::MultisetHash;
 pub use crate::portable::PortableHash;
-pub use crate::traits::HighwayHash;
+pub use crate::portable32::Portable32Hash;
+#[cfg(feature = "rand_core")]
+pub use crate::rng::HighwayRng;
+pub use crate::traits::{DynHighwayHash, HighwayHash, HighwayHashReset, ResetError};
+pub use crate::xof::XofReader;
 
 #[cfg(target_arch = "aarch64")]
 mod aarch64;
+#[cfg(feature = "nightly-simd")]
+mod simd;
 #[cfg(all(target_family = "wasm", target_feature = "simd128"))]
 mod wasm;
-#[cfg(target_arch = "x86_64")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod x86;
 
 #[cfg(target_arch = "aarch64")]
 pub use crate::aarch64::NeonHash;
-#[cfg(target_arch = "x86_64")]
+#[cfg(feature = "nightly-simd")]
+pub use crate::simd::SimdHash;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use crate::x86::{AvxHash, SseHash};
 
 #[cfg(all(target_family = "wasm", target_feature = "simd128"))]
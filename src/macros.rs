@@ -1,24 +1,144 @@
 macro_rules! impl_write {
     ($hasher_struct:ty) => {
+        impl ::core::fmt::Write for $hasher_struct {
+            fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+                $crate::HighwayHash::append(self, s.as_bytes());
+                Ok(())
+            }
+        }
+
+        #[cfg(feature = "embedded-io")]
+        impl ::embedded_io::ErrorType for $hasher_struct {
+            type Error = ::core::convert::Infallible;
+        }
+
+        #[cfg(feature = "embedded-io")]
+        impl ::embedded_io::Write for $hasher_struct {
+            fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+                $crate::HighwayHash::append(self, buf);
+                Ok(buf.len())
+            }
+
+            fn write_all(&mut self, buf: &[u8]) -> Result<(), Self::Error> {
+                $crate::HighwayHash::append(self, buf);
+                Ok(())
+            }
+
+            fn flush(&mut self) -> Result<(), Self::Error> {
+                Ok(())
+            }
+        }
+
         #[cfg(feature = "std")]
         impl ::std::io::Write for $hasher_struct {
             fn write(&mut self, bytes: &[u8]) -> ::std::io::Result<usize> {
                 $crate::HighwayHash::append(self, bytes);
                 Ok(bytes.len())
             }
+            fn write_vectored(
+                &mut self,
+                bufs: &[::std::io::IoSlice<'_>],
+            ) -> ::std::io::Result<usize> {
+                $crate::HighwayHash::append_vectored(self, bufs);
+                Ok(bufs.iter().map(|buf| buf.len()).sum())
+            }
             fn flush(&mut self) -> ::std::io::Result<()> {
                 Ok(())
             }
         }
+
+        #[cfg(all(feature = "std", feature = "tokio"))]
+        impl ::tokio::io::AsyncWrite for $hasher_struct {
+            fn poll_write(
+                self: ::core::pin::Pin<&mut Self>,
+                _cx: &mut ::core::task::Context<'_>,
+                buf: &[u8],
+            ) -> ::core::task::Poll<::std::io::Result<usize>> {
+                ::core::task::Poll::Ready(::std::io::Write::write(self.get_mut(), buf))
+            }
+
+            fn poll_write_vectored(
+                self: ::core::pin::Pin<&mut Self>,
+                _cx: &mut ::core::task::Context<'_>,
+                bufs: &[::std::io::IoSlice<'_>],
+            ) -> ::core::task::Poll<::std::io::Result<usize>> {
+                ::core::task::Poll::Ready(::std::io::Write::write_vectored(self.get_mut(), bufs))
+            }
+
+            fn is_write_vectored(&self) -> bool {
+                true
+            }
+
+            fn poll_flush(
+                self: ::core::pin::Pin<&mut Self>,
+                _cx: &mut ::core::task::Context<'_>,
+            ) -> ::core::task::Poll<::std::io::Result<()>> {
+                ::core::task::Poll::Ready(Ok(()))
+            }
+
+            fn poll_shutdown(
+                self: ::core::pin::Pin<&mut Self>,
+                _cx: &mut ::core::task::Context<'_>,
+            ) -> ::core::task::Poll<::std::io::Result<()>> {
+                ::core::task::Poll::Ready(Ok(()))
+            }
+        }
+
+        #[cfg(all(feature = "std", feature = "futures-io"))]
+        impl ::futures_io::AsyncWrite for $hasher_struct {
+            fn poll_write(
+                self: ::core::pin::Pin<&mut Self>,
+                _cx: &mut ::core::task::Context<'_>,
+                buf: &[u8],
+            ) -> ::core::task::Poll<::std::io::Result<usize>> {
+                ::core::task::Poll::Ready(::std::io::Write::write(self.get_mut(), buf))
+            }
+
+            fn poll_write_vectored(
+                self: ::core::pin::Pin<&mut Self>,
+                _cx: &mut ::core::task::Context<'_>,
+                bufs: &[::std::io::IoSlice<'_>],
+            ) -> ::core::task::Poll<::std::io::Result<usize>> {
+                ::core::task::Poll::Ready(::std::io::Write::write_vectored(self.get_mut(), bufs))
+            }
+
+            fn poll_flush(
+                self: ::core::pin::Pin<&mut Self>,
+                _cx: &mut ::core::task::Context<'_>,
+            ) -> ::core::task::Poll<::std::io::Result<()>> {
+                ::core::task::Poll::Ready(Ok(()))
+            }
+
+            fn poll_close(
+                self: ::core::pin::Pin<&mut Self>,
+                _cx: &mut ::core::task::Context<'_>,
+            ) -> ::core::task::Poll<::std::io::Result<()>> {
+                ::core::task::Poll::Ready(Ok(()))
+            }
+        }
     };
 }
 
 macro_rules! impl_hasher {
     ($hasher_struct:ty) => {
+        impl $hasher_struct {
+            /// Appends a fixed size array, copying it straight into the packet buffer when it
+            /// won't complete a packet
+            #[inline]
+            pub(crate) fn append_array<const N: usize>(&mut self, data: [u8; N]) {
+                if !self.buffer.try_push(data) {
+                    $crate::HighwayHash::append(self, &data);
+                }
+            }
+        }
+
         impl ::core::hash::Hasher for $hasher_struct {
             fn write(&mut self, bytes: &[u8]) {
                 $crate::HighwayHash::append(self, bytes);
             }
+
+            impl_hasher!(@write_ints);
+
             fn finish(&self) -> u64 {
                 // Reasons why we need to clone. finalize64` mutates internal state so either we need our
                 // Hasher to consume itself or receive a mutable reference on `finish`. We receive neither,
@@ -27,8 +147,31 @@ macro_rules! impl_hasher {
                 // there are several rounds of permutations when finalizing a value, and internal state is
                 // modified during that process. We work around these constraints by cloning the hasher and
                 // finalizing that one.
+                //
+                // There is no separate path for short inputs: whatever was written, finishing runs
+                // the full remainder and permutation rounds that HighwayHash specifies.
                 $crate::HighwayHash::finalize64(self.clone())
             }
         }
     };
+    (@write_ints) => {
+        impl_hasher!(@write_int write_u8, u8);
+        impl_hasher!(@write_int write_u16, u16);
+        impl_hasher!(@write_int write_u32, u32);
+        impl_hasher!(@write_int write_u64, u64);
+        impl_hasher!(@write_int write_u128, u128);
+        impl_hasher!(@write_int write_usize, usize);
+        impl_hasher!(@write_int write_i8, i8);
+        impl_hasher!(@write_int write_i16, i16);
+        impl_hasher!(@write_int write_i32, i32);
+        impl_hasher!(@write_int write_i64, i64);
+        impl_hasher!(@write_int write_i128, i128);
+        impl_hasher!(@write_int write_isize, isize);
+    };
+    (@write_int $name:ident, $int:ty) => {
+        #[inline]
+        fn $name(&mut self, i: $int) {
+            self.append_array(i.to_ne_bytes());
+        }
+    };
 }
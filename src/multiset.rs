@@ -0,0 +1,140 @@
+use crate::builder::{HighwayHasher, PreparedKey};
+use crate::key::Key;
+use crate::traits::HighwayHash;
+
+/// An incremental, order-independent hash of a multiset of byte strings
+///
+/// Each element is hashed with the keyed `finalize256` and the results are summed modulo
+/// 2^256. Addition is commutative and invertible, so elements can be inserted in any order,
+/// removed again, and accumulators over disjoint parts of a collection can be merged. Inserting
+/// each element at most once makes this a set hash.
+///
+/// The sum is only as collision resistant as the key is secret: anyone who can compute element
+/// hashes can search for colliding combinations.
+///
+/// ```rust
+/// use highway::{Key, MultisetHash};
+/// let key = Key([1, 2, 3, 4]);
+///
+/// let mut a = MultisetHash::new(key);
+/// a.insert(b"one");
+/// a.insert(b"two");
+///
+/// let mut b = MultisetHash::new(key);
+/// b.insert(b"two");
+/// b.insert(b"three");
+/// b.insert(b"one");
+/// b.remove(b"three");
+///
+/// assert_eq!(a.digest(), b.digest());
+/// ```
+#[derive(Debug, Clone)]
+pub struct MultisetHash {
+    key: PreparedKey,
+    sum: [u64; 4],
+    len: u64,
+}
+
+impl MultisetHash {
+    /// Creates an accumulator for the empty multiset
+    #[must_use]
+    pub fn new(key: Key) -> Self {
+        MultisetHash {
+            key: PreparedKey::new(key),
+            sum: [0; 4],
+            len: 0,
+        }
+    }
+
+    /// The key elements are hashed with
+    #[must_use]
+    pub fn key(&self) -> Key {
+        self.key.key()
+    }
+
+    /// The number of elements in the multiset
+    #[must_use]
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns true if the multiset holds no elements
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Adds an element
+    pub fn insert(&mut self, element: &[u8]) {
+        let hash = self.key.hash256(element);
+        self.sum = add(self.sum, hash);
+        self.len = self.len.wrapping_add(1);
+    }
+
+    /// Removes an element. Removing an element that was never inserted leaves the accumulator
+    /// in a state no sequence of insertions can reach.
+    pub fn remove(&mut self, element: &[u8]) {
+        let hash = self.key.hash256(element);
+        self.sum = sub(self.sum, hash);
+        self.len = self.len.wrapping_sub(1);
+    }
+
+    /// Adds every element of `other`, as if they had been inserted into `self`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the accumulators use different keys
+    pub fn merge(&mut self, other: &MultisetHash) {
+        assert_eq!(
+            self.key(),
+            other.key(),
+            "multiset hashes use different keys"
+        );
+        self.sum = add(self.sum, other.sum);
+        self.len = self.len.wrapping_add(other.len);
+    }
+
+    /// Returns the fixed-width digest of the multiset, which commits to both the element sum
+    /// and the number of elements
+    #[must_use]
+    pub fn digest(&self) -> [u64; 4] {
+        let mut hasher: HighwayHasher = self.key.hasher();
+        hasher.append_u64_le(&self.sum);
+        hasher.append_u64_le(&[self.len]);
+        hasher.finalize256()
+    }
+}
+
+impl PartialEq for MultisetHash {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key() && self.sum == other.sum && self.len == other.len
+    }
+}
+
+impl Eq for MultisetHash {}
+
+/// Adds two 256bit little endian integers modulo 2^256
+fn add(a: [u64; 4], b: [u64; 4]) -> [u64; 4] {
+    let mut result = [0u64; 4];
+    let mut carry = false;
+    for i in 0..4 {
+        let (sum, c1) = a[i].overflowing_add(b[i]);
+        let (sum, c2) = sum.overflowing_add(u64::from(carry));
+        result[i] = sum;
+        carry = c1 || c2;
+    }
+    result
+}
+
+/// Subtracts two 256bit little endian integers modulo 2^256
+fn sub(a: [u64; 4], b: [u64; 4]) -> [u64; 4] {
+    let mut result = [0u64; 4];
+    let mut borrow = false;
+    for i in 0..4 {
+        let (diff, b1) = a[i].overflowing_sub(b[i]);
+        let (diff, b2) = diff.overflowing_sub(u64::from(borrow));
+        result[i] = diff;
+        borrow = b1 || b2;
+    }
+    result
+}
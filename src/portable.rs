@@ -1,6 +1,7 @@
 use crate::internal::{HashPacket, PACKET_SIZE};
 use crate::key::Key;
-use crate::traits::HighwayHash;
+use crate::traits::{HighwayHash, HighwayHashReset, ResetError};
+use core::ops::Deref;
 
 /// Hardware agnostic HighwayHash implementation.
 ///
@@ -10,13 +11,14 @@ use crate::traits::HighwayHash;
 ///
 /// The main reason for directly using `PortableHash` would be if avoiding
 /// `unsafe` code blocks is a top priority.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct PortableHash {
     pub(crate) v0: [u64; 4],
     pub(crate) v1: [u64; 4],
     pub(crate) mul0: [u64; 4],
     pub(crate) mul1: [u64; 4],
     pub(crate) buffer: HashPacket,
+    pub(crate) key: Option<Key>,
 }
 
 impl HighwayHash for PortableHash {
@@ -25,6 +27,11 @@ impl HighwayHash for PortableHash {
         self.append(data);
     }
 
+    #[inline]
+    fn append_vectored<T: Deref<Target = [u8]>>(&mut self, fragments: &[T]) {
+        self.append_vectored(fragments);
+    }
+
     #[inline]
     fn finalize64(mut self) -> u64 {
         Self::finalize64(&mut self)
@@ -61,39 +68,66 @@ impl HighwayHash for PortableHash {
     }
 }
 
+impl Default for PortableHash {
+    fn default() -> Self {
+        PortableHash::new(Key::default())
+    }
+}
+
+impl HighwayHashReset for PortableHash {
+    #[inline]
+    fn finalize64_reset(&mut self) -> Result<u64, ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        let hash = Self::finalize64(self);
+        self.rekey(key);
+        Ok(hash)
+    }
+
+    #[inline]
+    fn finalize128_reset(&mut self) -> Result<[u64; 2], ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        let hash = Self::finalize128(self);
+        self.rekey(key);
+        Ok(hash)
+    }
+
+    #[inline]
+    fn finalize256_reset(&mut self) -> Result<[u64; 4], ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        let hash = Self::finalize256(self);
+        self.rekey(key);
+        Ok(hash)
+    }
+
+    #[inline]
+    fn reset(&mut self) -> Result<(), ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        self.rekey(key);
+        Ok(())
+    }
+
+    #[inline]
+    fn rekey(&mut self, key: Key) {
+        *self = Self::new(key);
+    }
+}
+
 impl PortableHash {
     /// Create a new `PortableHash` from a `Key`
     #[must_use]
-    pub fn new(key: Key) -> Self {
-        let mul0 = [
-            0xdbe6_d5d5_fe4c_ce2f,
-            0xa409_3822_299f_31d0,
-            0x1319_8a2e_0370_7344,
-            0x243f_6a88_85a3_08d3,
-        ];
-        let mul1 = [
-            0x3bd3_9e10_cb0e_f593,
-            0xc0ac_f169_b5f1_8a8c,
-            0xbe54_66cf_34e9_0c6c,
-            0x4528_21e6_38d0_1377,
-        ];
+    pub const fn new(key: Key) -> Self {
+        PortableHash::from_state(State::new(&key), key)
+    }
 
+    /// Creates a `PortableHash` from the initial state already computed for `key`
+    pub(crate) const fn from_state(state: State, key: Key) -> Self {
         PortableHash {
-            v0: [
-                mul0[0] ^ key[0],
-                mul0[1] ^ key[1],
-                mul0[2] ^ key[2],
-                mul0[3] ^ key[3],
-            ],
-            v1: [
-                mul1[0] ^ key[0].rotate_left(32),
-                mul1[1] ^ key[1].rotate_left(32),
-                mul1[2] ^ key[2].rotate_left(32),
-                mul1[3] ^ key[3].rotate_left(32),
-            ],
-            mul0,
-            mul1,
-            buffer: HashPacket::default(),
+            v0: state.v0,
+            v1: state.v1,
+            mul0: state.mul0,
+            mul1: state.mul1,
+            buffer: HashPacket::new(),
+            key: Some(key),
         }
     }
 
@@ -127,6 +161,18 @@ impl PortableHash {
             mul0,
             mul1,
             buffer,
+            key: None,
+        }
+    }
+
+    /// Create hasher from checkpointed state and the key it was created with. A checkpoint does
+    /// not record the key, so supplying it here lets [`HighwayHashReset::reset`] restore the
+    /// keyed initial state.
+    #[must_use]
+    pub fn from_checkpoint_with_key(data: [u8; 164], key: Key) -> Self {
+        Self {
+            key: Some(key),
+            ..Self::from_checkpoint(data)
         }
     }
 
@@ -135,14 +181,8 @@ impl PortableHash {
             self.update_remainder();
         }
 
-        for _i in 0..4 {
-            self.permute_and_update();
-        }
-
-        self.v0[0]
-            .wrapping_add(self.v1[0])
-            .wrapping_add(self.mul0[0])
-            .wrapping_add(self.mul1[0])
+        self.permute_rounds(4);
+        self.state().reduce64()
     }
 
     pub(crate) fn finalize128(&mut self) -> [u64; 2] {
@@ -150,21 +190,8 @@ impl PortableHash {
             self.update_remainder();
         }
 
-        for _i in 0..6 {
-            self.permute_and_update();
-        }
-
-        let low = self.v0[0]
-            .wrapping_add(self.mul0[0])
-            .wrapping_add(self.v1[2])
-            .wrapping_add(self.mul1[2]);
-
-        let high = self.v0[1]
-            .wrapping_add(self.mul0[1])
-            .wrapping_add(self.v1[3])
-            .wrapping_add(self.mul1[3]);
-
-        [low, high]
+        self.permute_rounds(6);
+        self.state().reduce128()
     }
 
     pub(crate) fn finalize256(&mut self) -> [u64; 4] {
@@ -172,34 +199,49 @@ impl PortableHash {
             self.update_remainder();
         }
 
-        for _i in 0..10 {
-            self.permute_and_update();
-        }
+        self.permute_rounds(10);
+        self.reduce256()
+    }
 
-        let (lowest, low) = PortableHash::module_reduction(
-            self.v1[1].wrapping_add(self.mul1[1]),
-            self.v1[0].wrapping_add(self.mul1[0]),
-            self.v0[1].wrapping_add(self.mul0[1]),
-            self.v0[0].wrapping_add(self.mul0[0]),
-        );
-        let (high, highest) = PortableHash::module_reduction(
-            self.v1[3].wrapping_add(self.mul1[3]),
-            self.v1[2].wrapping_add(self.mul1[2]),
-            self.v0[3].wrapping_add(self.mul0[3]),
-            self.v0[2].wrapping_add(self.mul0[2]),
-        );
+    /// Runs additional permutation rounds, used to squeeze extendable output
+    pub(crate) fn permute_rounds(&mut self, rounds: usize) {
+        self.set_state(self.state().permute_and_update(rounds));
+    }
 
-        [lowest, low, high, highest]
+    /// Folds all four lane groups into 256 bits as the final step of `finalize256`
+    pub(crate) fn reduce256(&self) -> [u64; 4] {
+        self.state().reduce256()
+    }
+
+    const fn state(&self) -> State {
+        State {
+            v0: self.v0,
+            v1: self.v1,
+            mul0: self.mul0,
+            mul1: self.mul1,
+        }
+    }
+
+    fn set_state(&mut self, state: State) {
+        self.v0 = state.v0;
+        self.v1 = state.v1;
+        self.mul0 = state.mul0;
+        self.mul1 = state.mul1;
     }
 
-    const fn module_reduction(a3_unmasked: u64, a2: u64, a1: u64, a0: u64) -> (u64, u64) {
+    pub(crate) const fn module_reduction(
+        a3_unmasked: u64,
+        a2: u64,
+        a1: u64,
+        a0: u64,
+    ) -> (u64, u64) {
         let a3 = a3_unmasked & 0x3FFF_FFFF_FFFF_FFFF;
         let high = a1 ^ ((a3 << 1) | (a2 >> 63)) ^ ((a3 << 2) | (a2 >> 62));
         let low = a0 ^ (a2 << 1) ^ (a2 << 2);
         (low, high)
     }
 
-    const fn permute(v: &[u64; 4]) -> [u64; 4] {
+    pub(crate) const fn permute(v: &[u64; 4]) -> [u64; 4] {
         [
             v[2].rotate_left(32),
             v[3].rotate_left(32),
@@ -208,39 +250,25 @@ impl PortableHash {
         ]
     }
 
-    fn permute_and_update(&mut self) {
-        let permuted: [u64; 4] = PortableHash::permute(&self.v0);
-        self.update(permuted);
-    }
-
     fn update(&mut self, lanes: [u64; 4]) {
-        for (i, lane) in lanes.iter().enumerate() {
-            self.v1[i] = self.v1[i].wrapping_add(*lane);
-        }
-
-        for i in 0..4 {
-            self.v1[i] = self.v1[i].wrapping_add(self.mul0[i]);
-        }
-
-        for i in 0..4 {
-            self.mul0[i] ^= (self.v1[i] & 0xffff_ffff).wrapping_mul(self.v0[i] >> 32);
-        }
-
-        for i in 0..4 {
-            self.v0[i] = self.v0[i].wrapping_add(self.mul1[i]);
-        }
+        self.set_state(self.state().update(lanes));
+    }
 
-        for i in 0..4 {
-            self.mul1[i] ^= (self.v0[i] & 0xffff_ffff).wrapping_mul(self.v1[i] >> 32);
+    #[inline]
+    fn update_packet(&mut self, packet: &[u8]) {
+        // Callers only pass whole packets, for which the conversion always succeeds
+        if let Ok(packet) = packet.try_into() {
+            self.update(PortableHash::data_to_lanes(packet));
         }
-
-        PortableHash::zipper_merge_and_add(self.v1[1], self.v1[0], &mut self.v0, 1, 0);
-        PortableHash::zipper_merge_and_add(self.v1[3], self.v1[2], &mut self.v0, 3, 2);
-        PortableHash::zipper_merge_and_add(self.v0[1], self.v0[0], &mut self.v1, 1, 0);
-        PortableHash::zipper_merge_and_add(self.v0[3], self.v0[2], &mut self.v1, 3, 2);
     }
 
-    fn zipper_merge_and_add(v1: u64, v0: u64, lane: &mut [u64; 4], add1: usize, add0: usize) {
+    const fn zipper_merge_and_add(
+        v1: u64,
+        v0: u64,
+        mut lane: [u64; 4],
+        add1: usize,
+        add0: usize,
+    ) -> [u64; 4] {
         lane[add0] = lane[add0].wrapping_add(
             (((v0 & 0xff00_0000) | (v1 & 0x00ff_0000_0000)) >> 24)
                 | (((v0 & 0xff00_0000_0000) | (v1 & 0x00ff_0000_0000_0000)) >> 16)
@@ -258,87 +286,251 @@ impl PortableHash {
                 | ((v1 & 0xff) << 48)
                 | (v0 & 0xff00_0000_0000_0000),
         );
+        lane
     }
 
-    fn data_to_lanes(d: &[u8]) -> [u64; 4] {
+    #[inline]
+    pub(crate) const fn data_to_lanes(d: &[u8; PACKET_SIZE]) -> [u64; 4] {
         let mut result = [0u64; 4];
-        for (x, dest) in d.chunks_exact(8).zip(result.iter_mut()) {
-            *dest = u64::from_le_bytes([x[0], x[1], x[2], x[3], x[4], x[5], x[6], x[7]]);
+        let mut i = 0;
+        while i < 4 {
+            let o = i * 8;
+            result[i] = u64::from_le_bytes([
+                d[o],
+                d[o + 1],
+                d[o + 2],
+                d[o + 3],
+                d[o + 4],
+                d[o + 5],
+                d[o + 6],
+                d[o + 7],
+            ]);
+            i += 1;
         }
         result
     }
 
-    fn rotate_32_by(count: u64, lanes: &mut [u64; 4]) {
-        for lane in lanes.iter_mut() {
-            let half0: u32 = *lane as u32;
-            let half1: u32 = (*lane >> 32) as u32;
-            *lane = u64::from((half0 << count) | (half0 >> (32 - count)));
-            *lane |= u64::from((half1 << count) | (half1 >> (32 - count))) << 32;
-        }
-    }
-
-    fn update_lanes(&mut self, size: u64) {
-        for i in 0..4 {
-            self.v0[i] = self.v0[i].wrapping_add((size << 32) + size);
+    const fn rotate_32_by(count: u64, mut lanes: [u64; 4]) -> [u64; 4] {
+        let mut i = 0;
+        while i < 4 {
+            let half0 = (lanes[i] as u32).rotate_left(count as u32);
+            let half1 = ((lanes[i] >> 32) as u32).rotate_left(count as u32);
+            lanes[i] = (half0 as u64) | ((half1 as u64) << 32);
+            i += 1;
         }
-
-        PortableHash::rotate_32_by(size, &mut self.v1);
+        lanes
     }
 
-    fn remainder(bytes: &[u8]) -> [u8; 32] {
+    /// Pads the first `len` bytes of `bytes` into the final packet
+    pub(crate) const fn remainder(bytes: &[u8; PACKET_SIZE], len: usize) -> [u8; 32] {
         let mut packet: [u8; 32] = [0u8; 32];
-        if bytes.len() > packet.len() {
+        if len >= PACKET_SIZE {
             debug_assert!(false, "remainder bytes must be less than 32");
             return packet;
         }
 
-        let size_mod4 = bytes.len() & 3;
-        let remainder_jump = bytes.len() & !3;
-        let remainder = &bytes[remainder_jump..];
-        let size = bytes.len() as u64;
+        let size_mod4 = len & 3;
+        let remainder_jump = len & !3;
 
-        packet[..remainder_jump].clone_from_slice(&bytes[..remainder_jump]);
-        if size & 16 != 0 {
-            let muxed = packet[28..]
-                .iter_mut()
-                .zip(&bytes[remainder_jump + size_mod4 - 4..]);
+        let mut i = 0;
+        while i < remainder_jump {
+            packet[i] = bytes[i];
+            i += 1;
+        }
 
-            for (p, b) in muxed {
-                *p = *b;
+        if len & 16 != 0 {
+            let mut i = 0;
+            while i < 4 {
+                packet[28 + i] = bytes[len - 4 + i];
+                i += 1;
             }
         } else if size_mod4 != 0 {
-            packet[16] = remainder[0];
-            packet[16 + 1] = remainder[size_mod4 >> 1];
-            packet[16 + 2] = remainder[size_mod4 - 1];
+            packet[16] = bytes[remainder_jump];
+            packet[16 + 1] = bytes[remainder_jump + (size_mod4 >> 1)];
+            packet[16 + 2] = bytes[len - 1];
         }
 
         packet
     }
 
     fn update_remainder(&mut self) {
-        let size = self.buffer.len() as u64;
-        self.update_lanes(size);
-        let packet = PortableHash::remainder(self.buffer.as_slice());
-        self.update(PortableHash::data_to_lanes(&packet));
+        let state = self
+            .state()
+            .update_remainder(self.buffer.inner(), self.buffer.len());
+        self.set_state(state);
     }
 
     fn append(&mut self, data: &[u8]) {
         if self.buffer.is_empty() {
             let mut chunks = data.chunks_exact(PACKET_SIZE);
             for chunk in chunks.by_ref() {
-                self.update(Self::data_to_lanes(chunk));
+                self.update_packet(chunk);
             }
             self.buffer.set_to(chunks.remainder());
         } else if let Some(tail) = self.buffer.fill(data) {
             self.update(Self::data_to_lanes(self.buffer.inner()));
             let mut chunks = tail.chunks_exact(PACKET_SIZE);
             for chunk in chunks.by_ref() {
-                self.update(Self::data_to_lanes(chunk));
+                self.update_packet(chunk);
             }
 
             self.buffer.set_to(chunks.remainder());
         }
     }
+
+    fn append_vectored<T: Deref<Target = [u8]>>(&mut self, fragments: &[T]) {
+        let mut buffer = self.buffer;
+        buffer.assemble(fragments, |packet| self.update_packet(packet));
+        self.buffer = buffer;
+    }
+}
+
+/// The lanes of the hash state, without the packet buffer. The algorithm is written once as
+/// `const fn` over this state so that the const hashers (see `hash64_const`) share it with
+/// `PortableHash`. Const functions can't take mutable references, so the state is `Copy` and
+/// passed by value, and loops are written with `while` as iterators aren't available either.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct State {
+    v0: [u64; 4],
+    v1: [u64; 4],
+    mul0: [u64; 4],
+    mul1: [u64; 4],
+}
+
+impl State {
+    pub(crate) const fn new(key: &Key) -> Self {
+        let mul0 = [
+            0xdbe6_d5d5_fe4c_ce2f,
+            0xa409_3822_299f_31d0,
+            0x1319_8a2e_0370_7344,
+            0x243f_6a88_85a3_08d3,
+        ];
+        let mul1 = [
+            0x3bd3_9e10_cb0e_f593,
+            0xc0ac_f169_b5f1_8a8c,
+            0xbe54_66cf_34e9_0c6c,
+            0x4528_21e6_38d0_1377,
+        ];
+
+        let key = key.0;
+        State {
+            v0: [
+                mul0[0] ^ key[0],
+                mul0[1] ^ key[1],
+                mul0[2] ^ key[2],
+                mul0[3] ^ key[3],
+            ],
+            v1: [
+                mul1[0] ^ key[0].rotate_left(32),
+                mul1[1] ^ key[1].rotate_left(32),
+                mul1[2] ^ key[2].rotate_left(32),
+                mul1[3] ^ key[3].rotate_left(32),
+            ],
+            mul0,
+            mul1,
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) const fn update(mut self, lanes: [u64; 4]) -> Self {
+        let mut i = 0;
+        while i < 4 {
+            self.v1[i] = self.v1[i].wrapping_add(lanes[i]);
+            i += 1;
+        }
+
+        let mut i = 0;
+        while i < 4 {
+            self.v1[i] = self.v1[i].wrapping_add(self.mul0[i]);
+            i += 1;
+        }
+
+        let mut i = 0;
+        while i < 4 {
+            self.mul0[i] ^= (self.v1[i] & 0xffff_ffff).wrapping_mul(self.v0[i] >> 32);
+            i += 1;
+        }
+
+        let mut i = 0;
+        while i < 4 {
+            self.v0[i] = self.v0[i].wrapping_add(self.mul1[i]);
+            i += 1;
+        }
+
+        let mut i = 0;
+        while i < 4 {
+            self.mul1[i] ^= (self.v0[i] & 0xffff_ffff).wrapping_mul(self.v1[i] >> 32);
+            i += 1;
+        }
+
+        self.v0 = PortableHash::zipper_merge_and_add(self.v1[1], self.v1[0], self.v0, 1, 0);
+        self.v0 = PortableHash::zipper_merge_and_add(self.v1[3], self.v1[2], self.v0, 3, 2);
+        self.v1 = PortableHash::zipper_merge_and_add(self.v0[1], self.v0[0], self.v1, 1, 0);
+        self.v1 = PortableHash::zipper_merge_and_add(self.v0[3], self.v0[2], self.v1, 3, 2);
+        self
+    }
+
+    /// Absorbs the final, partial packet made of the first `len` bytes of `bytes`
+    pub(crate) const fn update_remainder(mut self, bytes: &[u8; PACKET_SIZE], len: usize) -> Self {
+        let size = len as u64;
+        let mut i = 0;
+        while i < 4 {
+            self.v0[i] = self.v0[i].wrapping_add((size << 32) + size);
+            i += 1;
+        }
+
+        self.v1 = PortableHash::rotate_32_by(size, self.v1);
+        let packet = PortableHash::remainder(bytes, len);
+        self.update(PortableHash::data_to_lanes(&packet))
+    }
+
+    pub(crate) const fn permute_and_update(mut self, rounds: usize) -> Self {
+        let mut i = 0;
+        while i < rounds {
+            let permuted = PortableHash::permute(&self.v0);
+            self = self.update(permuted);
+            i += 1;
+        }
+        self
+    }
+
+    pub(crate) const fn reduce64(&self) -> u64 {
+        self.v0[0]
+            .wrapping_add(self.v1[0])
+            .wrapping_add(self.mul0[0])
+            .wrapping_add(self.mul1[0])
+    }
+
+    pub(crate) const fn reduce128(&self) -> [u64; 2] {
+        let low = self.v0[0]
+            .wrapping_add(self.mul0[0])
+            .wrapping_add(self.v1[2])
+            .wrapping_add(self.mul1[2]);
+
+        let high = self.v0[1]
+            .wrapping_add(self.mul0[1])
+            .wrapping_add(self.v1[3])
+            .wrapping_add(self.mul1[3]);
+
+        [low, high]
+    }
+
+    pub(crate) const fn reduce256(&self) -> [u64; 4] {
+        let (lowest, low) = PortableHash::module_reduction(
+            self.v1[1].wrapping_add(self.mul1[1]),
+            self.v1[0].wrapping_add(self.mul1[0]),
+            self.v0[1].wrapping_add(self.mul0[1]),
+            self.v0[0].wrapping_add(self.mul0[0]),
+        );
+        let (high, highest) = PortableHash::module_reduction(
+            self.v1[3].wrapping_add(self.mul1[3]),
+            self.v1[2].wrapping_add(self.mul1[2]),
+            self.v0[3].wrapping_add(self.mul0[3]),
+            self.v0[2].wrapping_add(self.mul0[2]),
+        );
+
+        [lowest, low, high, highest]
+    }
 }
 
 impl_write!(PortableHash);
@@ -0,0 +1,355 @@
+use crate::internal::{HashPacket, PACKET_SIZE};
+use crate::key::Key;
+use crate::portable::PortableHash;
+use crate::traits::{HighwayHash, HighwayHashReset, ResetError};
+use core::ops::Deref;
+
+/// Hardware agnostic HighwayHash implementation for 32-bit targets.
+///
+/// Each 64-bit lane is stored as a pair of `u32` halves so that the hot loop
+/// only uses 32x32->64 bit multiplies. Output is identical to `PortableHash`.
+/// `HighwayHasher` selects this implementation on targets where
+/// `target_pointer_width = "32"` when no SIMD implementation is available.
+#[derive(Debug, Clone, Copy)]
+pub struct Portable32Hash {
+    v0: [u32; 8],
+    v1: [u32; 8],
+    mul0: [u32; 8],
+    mul1: [u32; 8],
+    buffer: HashPacket,
+    pub(crate) key: Option<Key>,
+}
+
+impl HighwayHash for Portable32Hash {
+    #[inline]
+    fn append(&mut self, data: &[u8]) {
+        self.append(data);
+    }
+
+    #[inline]
+    fn append_vectored<T: Deref<Target = [u8]>>(&mut self, fragments: &[T]) {
+        self.append_vectored(fragments);
+    }
+
+    #[inline]
+    fn finalize64(mut self) -> u64 {
+        Self::finalize64(&mut self)
+    }
+
+    #[inline]
+    fn finalize128(mut self) -> [u64; 2] {
+        Self::finalize128(&mut self)
+    }
+
+    #[inline]
+    fn finalize256(mut self) -> [u64; 4] {
+        Self::finalize256(&mut self)
+    }
+
+    #[inline]
+    fn checkpoint(&self) -> [u8; 164] {
+        self.to_portable().checkpoint()
+    }
+}
+
+impl Default for Portable32Hash {
+    fn default() -> Self {
+        Portable32Hash::new(Key::default())
+    }
+}
+
+impl HighwayHashReset for Portable32Hash {
+    #[inline]
+    fn finalize64_reset(&mut self) -> Result<u64, ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        let hash = Self::finalize64(self);
+        self.rekey(key);
+        Ok(hash)
+    }
+
+    #[inline]
+    fn finalize128_reset(&mut self) -> Result<[u64; 2], ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        let hash = Self::finalize128(self);
+        self.rekey(key);
+        Ok(hash)
+    }
+
+    #[inline]
+    fn finalize256_reset(&mut self) -> Result<[u64; 4], ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        let hash = Self::finalize256(self);
+        self.rekey(key);
+        Ok(hash)
+    }
+
+    #[inline]
+    fn reset(&mut self) -> Result<(), ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        self.rekey(key);
+        Ok(())
+    }
+
+    #[inline]
+    fn rekey(&mut self, key: Key) {
+        *self = Self::new(key);
+    }
+}
+
+impl Portable32Hash {
+    /// Create a new `Portable32Hash` from a `Key`
+    #[must_use]
+    pub fn new(key: Key) -> Self {
+        Self::from_portable(&PortableHash::new(key))
+    }
+
+    /// Create hasher from checkpointed state
+    #[must_use]
+    pub fn from_checkpoint(data: [u8; 164]) -> Self {
+        Self::from_portable(&PortableHash::from_checkpoint(data))
+    }
+
+    /// Create hasher from checkpointed state and the key it was created with. A checkpoint does
+    /// not record the key, so supplying it here lets [`HighwayHashReset::reset`] restore the
+    /// keyed initial state.
+    #[must_use]
+    pub fn from_checkpoint_with_key(data: [u8; 164], key: Key) -> Self {
+        Self {
+            key: Some(key),
+            ..Self::from_checkpoint(data)
+        }
+    }
+
+    pub(crate) fn from_portable(portable: &PortableHash) -> Self {
+        Portable32Hash {
+            v0: split(&portable.v0),
+            v1: split(&portable.v1),
+            mul0: split(&portable.mul0),
+            mul1: split(&portable.mul1),
+            buffer: portable.buffer,
+            key: portable.key,
+        }
+    }
+
+    fn to_portable(self) -> PortableHash {
+        PortableHash {
+            v0: join(&self.v0),
+            v1: join(&self.v1),
+            mul0: join(&self.mul0),
+            mul1: join(&self.mul1),
+            buffer: self.buffer,
+            key: self.key,
+        }
+    }
+
+    pub(crate) fn finalize64(&mut self) -> u64 {
+        if !self.buffer.is_empty() {
+            self.update_remainder();
+        }
+
+        for _i in 0..4 {
+            self.permute_and_update();
+        }
+
+        lane(&self.v0, 0)
+            .wrapping_add(lane(&self.v1, 0))
+            .wrapping_add(lane(&self.mul0, 0))
+            .wrapping_add(lane(&self.mul1, 0))
+    }
+
+    pub(crate) fn finalize128(&mut self) -> [u64; 2] {
+        if !self.buffer.is_empty() {
+            self.update_remainder();
+        }
+
+        for _i in 0..6 {
+            self.permute_and_update();
+        }
+
+        let low = lane(&self.v0, 0)
+            .wrapping_add(lane(&self.mul0, 0))
+            .wrapping_add(lane(&self.v1, 2))
+            .wrapping_add(lane(&self.mul1, 2));
+
+        let high = lane(&self.v0, 1)
+            .wrapping_add(lane(&self.mul0, 1))
+            .wrapping_add(lane(&self.v1, 3))
+            .wrapping_add(lane(&self.mul1, 3));
+
+        [low, high]
+    }
+
+    pub(crate) fn finalize256(&mut self) -> [u64; 4] {
+        if !self.buffer.is_empty() {
+            self.update_remainder();
+        }
+
+        for _i in 0..10 {
+            self.permute_and_update();
+        }
+
+        let v0 = join(&self.v0);
+        let v1 = join(&self.v1);
+        let mul0 = join(&self.mul0);
+        let mul1 = join(&self.mul1);
+
+        let (lowest, low) = PortableHash::module_reduction(
+            v1[1].wrapping_add(mul1[1]),
+            v1[0].wrapping_add(mul1[0]),
+            v0[1].wrapping_add(mul0[1]),
+            v0[0].wrapping_add(mul0[0]),
+        );
+        let (high, highest) = PortableHash::module_reduction(
+            v1[3].wrapping_add(mul1[3]),
+            v1[2].wrapping_add(mul1[2]),
+            v0[3].wrapping_add(mul0[3]),
+            v0[2].wrapping_add(mul0[2]),
+        );
+
+        [lowest, low, high, highest]
+    }
+
+    #[inline]
+    fn permute(v: &[u32; 8]) -> [u32; 8] {
+        // Rotating a lane by 32 bits is a swap of its halves
+        [v[5], v[4], v[7], v[6], v[1], v[0], v[3], v[2]]
+    }
+
+    fn permute_and_update(&mut self) {
+        let permuted = Portable32Hash::permute(&self.v0);
+        self.update(permuted);
+    }
+
+    fn update(&mut self, lanes: [u32; 8]) {
+        for i in (0..8).step_by(2) {
+            add64(&mut self.v1, i, lanes[i], lanes[i + 1]);
+        }
+
+        for i in (0..8).step_by(2) {
+            add64(&mut self.v1, i, self.mul0[i], self.mul0[i + 1]);
+        }
+
+        for i in (0..8).step_by(2) {
+            let product = u64::from(self.v1[i]) * u64::from(self.v0[i + 1]);
+            self.mul0[i] ^= product as u32;
+            self.mul0[i + 1] ^= (product >> 32) as u32;
+        }
+
+        for i in (0..8).step_by(2) {
+            add64(&mut self.v0, i, self.mul1[i], self.mul1[i + 1]);
+        }
+
+        for i in (0..8).step_by(2) {
+            let product = u64::from(self.v0[i]) * u64::from(self.v1[i + 1]);
+            self.mul1[i] ^= product as u32;
+            self.mul1[i + 1] ^= (product >> 32) as u32;
+        }
+
+        Portable32Hash::zipper_merge_and_add(&self.v1, 0, &mut self.v0);
+        Portable32Hash::zipper_merge_and_add(&self.v1, 4, &mut self.v0);
+        Portable32Hash::zipper_merge_and_add(&self.v0, 0, &mut self.v1);
+        Portable32Hash::zipper_merge_and_add(&self.v0, 4, &mut self.v1);
+    }
+
+    /// Zipper merges the two lanes starting at the half `at` and adds the result to the
+    /// same lanes of `dest`.
+    #[inline]
+    fn zipper_merge_and_add(v: &[u32; 8], at: usize, dest: &mut [u32; 8]) {
+        let (v0lo, v0hi, v1lo, v1hi) = (v[at], v[at + 1], v[at + 2], v[at + 3]);
+
+        add64(
+            dest,
+            at,
+            (v0lo >> 24) | ((v1hi & 0xff) << 8) | (v0lo & 0x00ff_0000) | ((v0hi & 0xff00) << 16),
+            ((v1hi >> 16) & 0xff) | (v0lo & 0xff00) | ((v1hi >> 8) & 0x00ff_0000) | (v0lo << 24),
+        );
+
+        add64(
+            dest,
+            at + 2,
+            (v1lo >> 24) | ((v0hi & 0xff) << 8) | (v1lo & 0x00ff_0000) | ((v1hi & 0xff00) << 16),
+            ((v1lo >> 8) & 0xff)
+                | ((v0hi >> 8) & 0xff00)
+                | ((v1lo & 0xff) << 16)
+                | (v0hi & 0xff00_0000),
+        );
+    }
+
+    fn data_to_lanes(d: &[u8]) -> [u32; 8] {
+        let mut result = [0u32; 8];
+        for (x, dest) in d.chunks_exact(4).zip(result.iter_mut()) {
+            *dest = u32::from_le_bytes([x[0], x[1], x[2], x[3]]);
+        }
+        result
+    }
+
+    fn update_lanes(&mut self, size: u32) {
+        for i in (0..8).step_by(2) {
+            add64(&mut self.v0, i, size, size);
+        }
+
+        for half in self.v1.iter_mut() {
+            *half = half.rotate_left(size);
+        }
+    }
+
+    fn update_remainder(&mut self) {
+        let size = self.buffer.len() as u32;
+        self.update_lanes(size);
+        let packet = PortableHash::remainder(self.buffer.inner(), self.buffer.len());
+        self.update(Portable32Hash::data_to_lanes(&packet));
+    }
+
+    fn append(&mut self, data: &[u8]) {
+        if self.buffer.is_empty() {
+            let mut chunks = data.chunks_exact(PACKET_SIZE);
+            for chunk in chunks.by_ref() {
+                self.update(Self::data_to_lanes(chunk));
+            }
+            self.buffer.set_to(chunks.remainder());
+        } else if let Some(tail) = self.buffer.fill(data) {
+            self.update(Self::data_to_lanes(self.buffer.inner()));
+            let mut chunks = tail.chunks_exact(PACKET_SIZE);
+            for chunk in chunks.by_ref() {
+                self.update(Self::data_to_lanes(chunk));
+            }
+
+            self.buffer.set_to(chunks.remainder());
+        }
+    }
+
+    fn append_vectored<T: Deref<Target = [u8]>>(&mut self, fragments: &[T]) {
+        let mut buffer = self.buffer;
+        buffer.assemble(fragments, |packet| self.update(Self::data_to_lanes(packet)));
+        self.buffer = buffer;
+    }
+}
+
+/// Adds the 64-bit value `(hi, lo)` to the lane whose low half is at `at`
+#[inline]
+fn add64(dest: &mut [u32; 8], at: usize, lo: u32, hi: u32) {
+    let (sum, carry) = dest[at].overflowing_add(lo);
+    dest[at] = sum;
+    dest[at + 1] = dest[at + 1].wrapping_add(hi).wrapping_add(u32::from(carry));
+}
+
+#[inline]
+fn lane(v: &[u32; 8], index: usize) -> u64 {
+    u64::from(v[index * 2]) | (u64::from(v[index * 2 + 1]) << 32)
+}
+
+fn split(v: &[u64; 4]) -> [u32; 8] {
+    let mut result = [0u32; 8];
+    for (x, dest) in v.iter().zip(result.chunks_exact_mut(2)) {
+        dest[0] = *x as u32;
+        dest[1] = (*x >> 32) as u32;
+    }
+    result
+}
+
+fn join(v: &[u32; 8]) -> [u64; 4] {
+    [lane(v, 0), lane(v, 1), lane(v, 2), lane(v, 3)]
+}
+
+impl_write!(Portable32Hash);
+impl_hasher!(Portable32Hash);
@@ -0,0 +1,224 @@
+//! Stable keyed tokens that replace identifiers (eg: user ids in analytics) without revealing
+//! them.
+//!
+//! A token is the keyed 128bit hash of a field's context and value, encoded as hex or base32
+//! and prefixed with the key's version, such as `v2_3mzq...`. The context keeps equal values
+//! in different fields from producing linkable tokens. Rotating the key bumps the version so
+//! old and new tokens can be told apart, while old keys can be kept around to match tokens
+//! issued before the rotation.
+//!
+//! ```rust
+//! use highway::pseudonym::Pseudonymizer;
+//! use highway::Key;
+//!
+//! let mut pseudonymizer = Pseudonymizer::new(Key([1, 2, 3, 4]), 1);
+//! let token = pseudonymizer.token("email", b"alice@example.com");
+//! assert!(token.starts_with("v1_"));
+//!
+//! pseudonymizer.rotate(Key([5, 6, 7, 8]), 2);
+//! assert!(pseudonymizer.token("email", b"alice@example.com").starts_with("v2_"));
+//! assert!(pseudonymizer.matches(&token, "email", b"alice@example.com"));
+//! ```
+
+use crate::builder::{HighwayHasher, PreparedKey};
+use crate::internal::{append_length_prefixed, constant_eq};
+use crate::key::Key;
+use crate::traits::HighwayHash;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+const HEX: &[u8; 16] = b"0123456789abcdef";
+const BASE32: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+/// How the 128 bits of a token are written out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenEncoding {
+    /// 32 lowercase hex digits
+    Hex,
+    /// 26 lowercase, unpadded RFC 4648 base32 characters
+    Base32,
+}
+
+impl TokenEncoding {
+    fn encode(self, bytes: &[u8; 16], out: &mut String) {
+        match self {
+            TokenEncoding::Hex => {
+                for byte in bytes {
+                    out.push(char::from(HEX[usize::from(byte >> 4)]));
+                    out.push(char::from(HEX[usize::from(byte & 0xf)]));
+                }
+            }
+            TokenEncoding::Base32 => {
+                let mut buffer = 0u16;
+                let mut bits = 0;
+                for &byte in bytes {
+                    buffer = (buffer << 8) | u16::from(byte);
+                    bits += 8;
+                    while bits >= 5 {
+                        bits -= 5;
+                        out.push(char::from(BASE32[usize::from((buffer >> bits) & 0x1f)]));
+                    }
+                }
+                if bits > 0 {
+                    out.push(char::from(
+                        BASE32[usize::from((buffer << (5 - bits)) & 0x1f)],
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Replaces field values with versioned, keyed tokens
+#[derive(Debug, Clone)]
+pub struct Pseudonymizer {
+    keys: Vec<(u32, PreparedKey)>,
+    encoding: TokenEncoding,
+}
+
+impl Pseudonymizer {
+    /// Creates a pseudonymizer issuing base32 tokens under `key`, labeled with `version`
+    #[must_use]
+    pub fn new(key: Key, version: u32) -> Self {
+        Pseudonymizer {
+            keys: alloc::vec![(version, PreparedKey::new(key))],
+            encoding: TokenEncoding::Base32,
+        }
+    }
+
+    /// Sets how tokens are encoded
+    #[must_use]
+    pub fn with_encoding(mut self, encoding: TokenEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// The version of the key new tokens are issued under
+    #[must_use]
+    pub fn version(&self) -> u32 {
+        self.keys[0].0
+    }
+
+    /// Issues new tokens under `key`, labeled with `version`. Previous keys are kept so that
+    /// [`Pseudonymizer::matches`] recognizes tokens issued before the rotation, until they are
+    /// retired. Rotating to a version that is already known replaces its key.
+    pub fn rotate(&mut self, key: Key, version: u32) {
+        self.keys.retain(|(v, _)| *v != version);
+        self.keys.insert(0, (version, PreparedKey::new(key)));
+    }
+
+    /// Forgets the key of a previous version. Returns false if the version is unknown or is
+    /// the current one.
+    pub fn retire(&mut self, version: u32) -> bool {
+        match self.keys.iter().skip(1).position(|(v, _)| *v == version) {
+            Some(index) => {
+                self.keys.remove(index + 1);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// A hasher that has absorbed the length prefixed context
+    fn context_hasher(key: &PreparedKey, context: &str) -> HighwayHasher {
+        let mut hasher = key.hasher();
+        append_length_prefixed(&mut hasher, context.as_bytes());
+        hasher
+    }
+
+    fn finish(&self, version: u32, hasher: HighwayHasher, out: &mut String) {
+        let [low, high] = hasher.finalize128();
+        let mut bytes = [0u8; 16];
+        bytes[..8].copy_from_slice(&low.to_le_bytes());
+        bytes[8..].copy_from_slice(&high.to_le_bytes());
+
+        // Writing to a string can't fail
+        let _ = write!(out, "v{}_", version);
+        self.encoding.encode(&bytes, out);
+    }
+
+    /// Returns the raw 128bit token of a value under the current key
+    #[must_use]
+    pub fn raw_token(&self, context: &str, value: &[u8]) -> [u64; 2] {
+        let mut hasher = Self::context_hasher(&self.keys[0].1, context);
+        hasher.append(value);
+        hasher.finalize128()
+    }
+
+    /// Returns the token of a value under the current key
+    #[must_use]
+    pub fn token(&self, context: &str, value: &[u8]) -> String {
+        let mut out = String::new();
+        let (version, key) = &self.keys[0];
+        let mut hasher = Self::context_hasher(key, context);
+        hasher.append(value);
+        self.finish(*version, hasher, &mut out);
+        out
+    }
+
+    /// Returns the token of an integer identifier, normalized to its 8 little endian bytes so
+    /// tokens don't depend on the platform or the width of the integer type it was stored in
+    #[must_use]
+    pub fn token_u64(&self, context: &str, value: u64) -> String {
+        self.token(context, &value.to_le_bytes())
+    }
+
+    /// Returns the token of a signed integer identifier, normalized to its 8 little endian
+    /// two's complement bytes
+    #[must_use]
+    pub fn token_i64(&self, context: &str, value: i64) -> String {
+        self.token(context, &value.to_le_bytes())
+    }
+
+    /// Appends the tokens of a batch of values from the same field to `out`. The context is
+    /// only hashed once for the whole batch.
+    pub fn tokens_into<I>(&self, context: &str, values: I, out: &mut Vec<String>)
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        let (version, key) = &self.keys[0];
+        let base = Self::context_hasher(key, context);
+        for value in values {
+            let mut hasher = base;
+            hasher.append(value.as_ref());
+            let mut token = String::new();
+            self.finish(*version, hasher, &mut token);
+            out.push(token);
+        }
+    }
+
+    /// Returns the tokens of a batch of values from the same field
+    #[must_use]
+    pub fn tokens<I>(&self, context: &str, values: I) -> Vec<String>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        let mut out = Vec::new();
+        self.tokens_into(context, values, &mut out);
+        out
+    }
+
+    /// Returns true if `token` was issued for this value and context under the current or a
+    /// previous, not yet retired, key, in the current encoding
+    #[must_use]
+    pub fn matches(&self, token: &str, context: &str, value: &[u8]) -> bool {
+        let version = token
+            .strip_prefix('v')
+            .and_then(|rest| rest.split('_').next())
+            .and_then(|version| version.parse::<u32>().ok());
+
+        let key = match version.and_then(|version| self.keys.iter().find(|(v, _)| *v == version)) {
+            Some(key) => key,
+            None => return false,
+        };
+
+        let mut hasher = Self::context_hasher(&key.1, context);
+        hasher.append(value);
+        let mut expected = String::new();
+        self.finish(key.0, hasher, &mut expected);
+        constant_eq(expected.as_bytes(), token.as_bytes())
+    }
+}
@@ -0,0 +1,96 @@
+use crate::builder::HighwayHasher;
+use crate::key::Key;
+use crate::traits::HighwayHash;
+use rand_core::{impls, RngCore, SeedableRng};
+
+/// A keyed pseudo-random number generator running HighwayHash in counter mode
+///
+/// Each block of four `u64` outputs is the 256bit hash of a little endian `u64` block counter,
+/// so the stream is identical across backends and platforms, and any block can be jumped to in
+/// constant time with [`HighwayRng::seek`]. Words are returned in order, `next_u32` truncates a
+/// `u64`, and `fill_bytes` writes little endian words.
+///
+/// HighwayHash is not a cryptographic primitive, so this generator deliberately does not
+/// implement `CryptoRng`. Use it for simulations, sampling, and tests, not for secrets.
+///
+/// ```rust
+/// use highway::{HighwayRng, Key};
+/// use rand_core::RngCore;
+///
+/// let mut rng = HighwayRng::new(Key([1, 2, 3, 4]));
+/// let first = rng.next_u64();
+/// let _ = rng.next_u64();
+///
+/// rng.seek(0);
+/// assert_eq!(rng.next_u64(), first);
+/// ```
+#[derive(Debug, Clone)]
+pub struct HighwayRng {
+    hasher: HighwayHasher,
+    counter: u64,
+    block: [u64; 4],
+    index: usize,
+}
+
+impl HighwayRng {
+    /// Creates a generator positioned at the start of the stream for the given key
+    #[must_use]
+    pub fn new(key: Key) -> Self {
+        HighwayRng {
+            hasher: HighwayHasher::new(key),
+            counter: 0,
+            block: [0; 4],
+            index: 4,
+        }
+    }
+
+    /// Jumps to the start of the given block. Each block holds four `u64` outputs.
+    pub fn seek(&mut self, block: u64) {
+        self.counter = block;
+        self.index = 4;
+    }
+
+    /// The block that the next output will be taken from
+    #[must_use]
+    pub fn block_pos(&self) -> u64 {
+        if self.index < 4 {
+            self.counter.wrapping_sub(1)
+        } else {
+            self.counter
+        }
+    }
+
+    fn generate(&mut self) {
+        self.block = self.hasher.hash256(&self.counter.to_le_bytes());
+        self.counter = self.counter.wrapping_add(1);
+        self.index = 0;
+    }
+}
+
+impl RngCore for HighwayRng {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        if self.index >= 4 {
+            self.generate();
+        }
+
+        let result = self.block[self.index];
+        self.index += 1;
+        result
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest);
+    }
+}
+
+impl SeedableRng for HighwayRng {
+    type Seed = [u8; 32];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        HighwayRng::new(Key::from_bytes(seed))
+    }
+}
@@ -0,0 +1,83 @@
+//! Deterministic, keyed sampling and experiment bucketing.
+//!
+//! Decisions only depend on the key and the input bytes, so they are stable across runs,
+//! machines, and backends, and can't be predicted without the key. The constructions are
+//! simple enough to reimplement in other languages on top of a HighwayHash library:
+//!
+//! - [`unit()`]: the top 53 bits of `hash64(id)`, divided by 2^53, giving a float in `[0, 1)`
+//! - [`should_sample`]: `unit(id) < rate`
+//! - [`salted_unit`] and [`bucket`] hash `len(salt) as u64 little endian || salt || id`. The
+//!   unit float is taken from `hash64` of that input, while the bucket is the 128bit hash
+//!   (`low | high << 64`) modulo `n`.
+//!
+//! Test vectors with the key `[1, 2, 3, 4]`:
+//!
+//! | function                              | result               |
+//! |---------------------------------------|----------------------|
+//! | `hash64(b"user-1")`                   | `0xd638834e8d4f9567` |
+//! | `unit(b"user-1")`                     | `0.8367998186852772` |
+//! | `unit(b"user-2")`                     | `0.2008944313648815` |
+//! | `salted_unit(b"checkout", b"user-1")` | `0.4394875479724081` |
+//! | `bucket(b"checkout", b"user-1", 10)`  | `5`                  |
+//! | `bucket(b"checkout", b"user-2", 10)`  | `2`                  |
+//!
+//! ```rust
+//! use highway::sampling::{bucket, should_sample};
+//! use highway::Key;
+//!
+//! let key = Key([1, 2, 3, 4]);
+//! let keep = should_sample(key, b"request-1234", 0.01);
+//! let arm = bucket(key, b"checkout-button-color", b"user-42", 3);
+//! assert!(arm < 3);
+//! ```
+
+use crate::builder::HighwayHasher;
+use crate::internal::append_length_prefixed;
+use crate::key::Key;
+use crate::traits::HighwayHash;
+
+/// Maps a 64bit hash to a float in `[0, 1)` with full 53 bit precision
+fn to_unit(hash: u64) -> f64 {
+    (hash >> 11) as f64 / (1u64 << 53) as f64
+}
+
+fn salted(key: Key, salt: &[u8], id: &[u8]) -> HighwayHasher {
+    let mut hasher = HighwayHasher::new(key);
+    append_length_prefixed(&mut hasher, salt);
+    hasher.append(id);
+    hasher
+}
+
+/// Returns a float in `[0, 1)` derived from the keyed 64bit hash of `id`
+#[must_use]
+pub fn unit(key: Key, id: &[u8]) -> f64 {
+    to_unit(HighwayHasher::new(key).hash64(id))
+}
+
+/// Returns a float in `[0, 1)` for `id` that is independent of the values for other salts
+/// (eg: experiment names)
+#[must_use]
+pub fn salted_unit(key: Key, salt: &[u8], id: &[u8]) -> f64 {
+    to_unit(salted(key, salt, id).finalize64())
+}
+
+/// Returns true for a `rate` fraction of ids. An id sampled at some rate is also sampled at
+/// every higher rate.
+#[must_use]
+pub fn should_sample(key: Key, id: &[u8], rate: f64) -> bool {
+    unit(key, id) < rate
+}
+
+/// Assigns `id` to one of `n` buckets of an experiment. Assignments in different experiments
+/// are independent.
+///
+/// # Panics
+///
+/// Panics if `n` is zero
+#[must_use]
+pub fn bucket(key: Key, experiment: &[u8], id: &[u8], n: u32) -> u32 {
+    assert!(n != 0, "number of buckets must be non-zero");
+    let [low, high] = salted(key, experiment, id).finalize128();
+    let hash = u128::from(low) | (u128::from(high) << 64);
+    (hash % u128::from(n)) as u32
+}
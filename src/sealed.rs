@@ -0,0 +1,223 @@
+//! Authenticated message framing: a payload followed by a keyed 128bit HighwayHash tag.
+//!
+//! The tag covers a fixed label, the length prefixed associated data, and the length prefixed
+//! payload, so no two different `(aad, payload)` pairs are ever hashed as the same bytes. The
+//! associated data (eg: a stream or message type identifier) is authenticated but not sent: the
+//! receiver must supply the same value to open the frame. Payloads are not encrypted.
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use highway::sealed::{open, seal, OpenError};
+//! use highway::Key;
+//!
+//! let key = Key([1, 2, 3, 4]);
+//! let frame = seal(key, b"telemetry/v1", b"cpu=0.5");
+//! assert_eq!(open(key, b"telemetry/v1", &frame), Ok(&b"cpu=0.5"[..]));
+//! assert_eq!(open(key, b"telemetry/v2", &frame), Err(OpenError::InvalidTag));
+//! # }
+//! ```
+//!
+//! With the `tokio-util` feature, `SealedCodec` length delimits sealed frames on a stream.
+
+use crate::builder::PreparedKey;
+use crate::internal::{append_label, append_length_prefixed, constant_eq};
+use crate::key::Key;
+use crate::traits::HighwayHash;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Size in bytes of the tag appended to every frame
+pub const TAG_SIZE: usize = 16;
+
+/// Label that starts every tag, see `append_label`
+const LABEL: &[u8] = b"highway-sealed";
+
+/// Reasons a frame can't be opened
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpenError {
+    /// The frame is shorter than a tag
+    TooShort,
+    /// The tag does not match the key, associated data, and payload
+    InvalidTag,
+}
+
+impl fmt::Display for OpenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OpenError::TooShort => f.write_str("frame is shorter than its tag"),
+            OpenError::InvalidTag => f.write_str("frame tag is invalid"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OpenError {}
+
+fn tag(key: &PreparedKey, aad: &[u8], payload: &[u8]) -> [u8; TAG_SIZE] {
+    let mut hasher = key.hasher();
+    append_label(&mut hasher, LABEL);
+    append_length_prefixed(&mut hasher, aad);
+    append_length_prefixed(&mut hasher, payload);
+    let [low, high] = hasher.finalize128();
+
+    let mut tag = [0u8; TAG_SIZE];
+    tag[..8].copy_from_slice(&low.to_le_bytes());
+    tag[8..].copy_from_slice(&high.to_le_bytes());
+    tag
+}
+
+fn open_prepared<'a>(
+    key: &PreparedKey,
+    aad: &[u8],
+    frame: &'a [u8],
+) -> Result<&'a [u8], OpenError> {
+    if frame.len() < TAG_SIZE {
+        return Err(OpenError::TooShort);
+    }
+
+    let (payload, received) = frame.split_at(frame.len() - TAG_SIZE);
+    if constant_eq(&tag(key, aad, payload), received) {
+        Ok(payload)
+    } else {
+        Err(OpenError::InvalidTag)
+    }
+}
+
+/// Returns the payload followed by its tag
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn seal(key: Key, aad: &[u8], payload: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(payload.len() + TAG_SIZE);
+    seal_into(key, aad, payload, &mut out);
+    out
+}
+
+/// Appends the payload followed by its tag to `out`
+#[cfg(feature = "alloc")]
+pub fn seal_into(key: Key, aad: &[u8], payload: &[u8], out: &mut Vec<u8>) {
+    let tag = tag(&PreparedKey::new(key), aad, payload);
+    out.extend_from_slice(payload);
+    out.extend_from_slice(&tag);
+}
+
+/// Verifies the tag of a sealed frame in constant time and returns its payload
+pub fn open<'a>(key: Key, aad: &[u8], frame: &'a [u8]) -> Result<&'a [u8], OpenError> {
+    open_prepared(&PreparedKey::new(key), aad, frame)
+}
+
+#[cfg(all(feature = "std", feature = "tokio-util"))]
+pub use self::codec::SealedCodec;
+
+#[cfg(all(feature = "std", feature = "tokio-util"))]
+mod codec {
+    use super::{open_prepared, tag, PreparedKey, TAG_SIZE};
+    use crate::key::Key;
+    use std::io;
+    use tokio_util::bytes::{Buf, BufMut, Bytes, BytesMut};
+    use tokio_util::codec::{Decoder, Encoder};
+
+    /// Default limit on the size of a sealed frame, tag included
+    const DEFAULT_MAX_FRAME_LENGTH: usize = 8 * 1024 * 1024;
+
+    /// A `tokio-util` codec that seals each outgoing payload and opens each incoming frame
+    ///
+    /// On the wire, every frame is a big endian `u32` length followed by the sealed frame.
+    /// Frames that fail verification, or that are longer than the maximum frame length, yield
+    /// an `InvalidData` error.
+    ///
+    /// Frames carry no sequence number and each one is authenticated on its own, so a frame that
+    /// is replayed, dropped, or reordered on the stream still opens successfully. Protocols that
+    /// care must put a counter in the payload and check it.
+    ///
+    /// ```rust
+    /// use highway::sealed::SealedCodec;
+    /// use highway::Key;
+    /// use tokio_util::bytes::BytesMut;
+    /// use tokio_util::codec::{Decoder, Encoder};
+    ///
+    /// let mut codec = SealedCodec::new(Key([1, 2, 3, 4]), b"telemetry/v1");
+    /// let mut buf = BytesMut::new();
+    /// codec.encode(&b"cpu=0.5"[..], &mut buf).unwrap();
+    /// let payload = codec.decode(&mut buf).unwrap().unwrap();
+    /// assert_eq!(&payload[..], b"cpu=0.5");
+    /// ```
+    #[derive(Debug, Clone)]
+    pub struct SealedCodec {
+        key: PreparedKey,
+        aad: Vec<u8>,
+        max_frame_length: usize,
+    }
+
+    impl SealedCodec {
+        /// Creates a codec that authenticates every frame with `key` and `aad`
+        #[must_use]
+        pub fn new(key: Key, aad: &[u8]) -> Self {
+            SealedCodec {
+                key: PreparedKey::new(key),
+                aad: aad.to_vec(),
+                max_frame_length: DEFAULT_MAX_FRAME_LENGTH,
+            }
+        }
+
+        /// Sets the largest sealed frame, tag included, that will be encoded or decoded.
+        /// Defaults to 8 MiB.
+        #[must_use]
+        pub fn max_frame_length(mut self, max_frame_length: usize) -> Self {
+            self.max_frame_length = max_frame_length;
+            self
+        }
+
+        fn frame_too_long(&self) -> io::Error {
+            io::Error::new(io::ErrorKind::InvalidData, "sealed frame is too long")
+        }
+    }
+
+    impl Decoder for SealedCodec {
+        type Item = Bytes;
+        type Error = io::Error;
+
+        fn decode(&mut self, src: &mut BytesMut) -> io::Result<Option<Bytes>> {
+            if src.len() < 4 {
+                return Ok(None);
+            }
+
+            let len = u32::from_be_bytes([src[0], src[1], src[2], src[3]]) as usize;
+            if len > self.max_frame_length {
+                return Err(self.frame_too_long());
+            }
+
+            if src.len() < 4 + len {
+                src.reserve(4 + len - src.len());
+                return Ok(None);
+            }
+
+            src.advance(4);
+            let mut frame = src.split_to(len);
+            let payload_len = open_prepared(&self.key, &self.aad, &frame)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+                .len();
+            frame.truncate(payload_len);
+            Ok(Some(frame.freeze()))
+        }
+    }
+
+    impl<T: AsRef<[u8]>> Encoder<T> for SealedCodec {
+        type Error = io::Error;
+
+        fn encode(&mut self, item: T, dst: &mut BytesMut) -> io::Result<()> {
+            let payload = item.as_ref();
+            let len = payload.len() + TAG_SIZE;
+            if len > self.max_frame_length || u32::try_from(len).is_err() {
+                return Err(self.frame_too_long());
+            }
+
+            dst.reserve(4 + len);
+            dst.put_u32(len as u32);
+            dst.extend_from_slice(payload);
+            dst.extend_from_slice(&tag(&self.key, &self.aad, payload));
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,232 @@
+//! Keyed sharding helpers: jump consistent hashing, rendezvous hashing, a consistent hash ring,
+//! and unbiased range reduction.
+//!
+//! Every placement is derived from a secret [`Key`], so without the key an adversary can't
+//! choose identifiers that all land on the same shard.
+//!
+//! ```rust
+//! use highway::shard::{hash_to_range, jump_bucket, rendezvous};
+//! use highway::Key;
+//!
+//! let key = Key([1, 2, 3, 4]);
+//! let bucket = jump_bucket(key, b"user-42", 10);
+//! assert!(bucket < 10);
+//!
+//! let nodes = ["node-a", "node-b", "node-c"];
+//! let node = rendezvous(key, b"user-42", &nodes).unwrap();
+//! assert!(nodes.contains(node));
+//!
+//! assert!(hash_to_range(key, b"user-42", 7) < 7);
+//! ```
+
+use crate::builder::{HighwayHasher, PreparedKey};
+use crate::internal::append_length_prefixed;
+use crate::key::Key;
+use crate::traits::HighwayHash;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Returns the bucket in `0..buckets` for `id` using jump consistent hashing (Lamping and
+/// Veach), seeded with the keyed 64bit hash of `id`. When the number of buckets grows from `n`
+/// to `n + 1`, only `1 / (n + 1)` of the ids move, and all of them to the new bucket.
+///
+/// # Panics
+///
+/// Panics if `buckets` is zero
+#[must_use]
+pub fn jump_bucket(key: Key, id: &[u8], buckets: u32) -> u32 {
+    assert!(buckets != 0, "number of buckets must be non-zero");
+    let mut seed = HighwayHasher::new(key).hash64(id);
+    let mut b: i64 = -1;
+    let mut j: i64 = 0;
+    while j < i64::from(buckets) {
+        b = j;
+        seed = seed.wrapping_mul(2_862_933_555_777_941_757).wrapping_add(1);
+        j = ((b + 1) as f64 * ((1u64 << 31) as f64 / ((seed >> 33) + 1) as f64)) as i64;
+    }
+    b as u32
+}
+
+/// Returns a number in `0..n` for `data`, uniformly distributed without modulo bias, using
+/// Lemire's multiply-shift reduction with rejection. Candidates are the lanes of the keyed
+/// 256bit hash, followed by its extendable output in the astronomically unlikely case that all
+/// four are rejected.
+///
+/// # Panics
+///
+/// Panics if `n` is zero
+#[must_use]
+pub fn hash_to_range(key: Key, data: &[u8], n: u64) -> u64 {
+    let mut hasher = HighwayHasher::new(key);
+    hasher.append(data);
+    let lanes = hasher.finalize256();
+
+    let mut candidates = lanes.iter().copied();
+    let mut xof = None;
+    reduce(n, || {
+        if let Some(x) = candidates.next() {
+            return x;
+        }
+
+        let reader = xof.get_or_insert_with(|| {
+            let mut reader = hasher.finalize_xof();
+            reader.fill(&mut [0u8; 32]);
+            reader
+        });
+        let mut bytes = [0u8; 8];
+        reader.fill(&mut bytes);
+        u64::from_le_bytes(bytes)
+    })
+}
+
+/// Lemire's nearly divisionless unbiased reduction of random words into `0..n`
+fn reduce<F: FnMut() -> u64>(n: u64, mut next: F) -> u64 {
+    assert!(n != 0, "range must be non-zero");
+    let mut m = u128::from(next()) * u128::from(n);
+    if (m as u64) < n {
+        let threshold = n.wrapping_neg() % n;
+        while (m as u64) < threshold {
+            m = u128::from(next()) * u128::from(n);
+        }
+    }
+    (m >> 64) as u64
+}
+
+/// Hashes a node and an id together. The node is length prefixed so that different splits of
+/// the same bytes don't collide.
+fn node_hash(key: &PreparedKey, node: &[u8], id: &[u8]) -> u64 {
+    let mut hasher = key.hasher();
+    append_length_prefixed(&mut hasher, node);
+    hasher.append(id);
+    hasher.finalize64()
+}
+
+/// Returns the node with the highest keyed score for `id` (rendezvous, or highest random
+/// weight, hashing), or `None` if there are no nodes. Removing a node only moves the ids that
+/// were assigned to it.
+#[must_use]
+pub fn rendezvous<'a, N: AsRef<[u8]>>(key: Key, id: &[u8], nodes: &'a [N]) -> Option<&'a N> {
+    let key = PreparedKey::new(key);
+    nodes
+        .iter()
+        .max_by_key(|node| node_hash(&key, node.as_ref(), id))
+}
+
+/// Returns the node with the highest weighted keyed score for `id`, or `None` if there are no
+/// nodes with a positive weight. Each node receives a share of ids proportional to its weight.
+///
+/// Uses the logarithmic method: a node's score is `-weight / ln(u)`, where `u` is its hash
+/// mapped into `(0, 1)`.
+#[cfg(feature = "std")]
+#[must_use]
+pub fn weighted_rendezvous<'a, N: AsRef<[u8]>>(
+    key: Key,
+    id: &[u8],
+    nodes: &'a [(N, f64)],
+) -> Option<&'a N> {
+    let key = PreparedKey::new(key);
+    let mut best: Option<(&N, f64)> = None;
+    for (node, weight) in nodes {
+        if weight.is_nan() || *weight <= 0.0 {
+            continue;
+        }
+
+        let hash = node_hash(&key, node.as_ref(), id);
+        let unit = ((hash >> 11) as f64 + 0.5) / (1u64 << 53) as f64;
+        let score = -weight / unit.ln();
+        match best {
+            Some((_, best_score)) if best_score >= score => {}
+            _ => best = Some((node, score)),
+        }
+    }
+    best.map(|(node, _)| node)
+}
+
+/// A consistent hash ring with virtual nodes
+///
+/// Each node is placed on the ring at `replicas` keyed points. An id is assigned to the node
+/// owning the first point at or after the id's hash, so adding or removing a node only moves
+/// the ids between it and its neighbors.
+///
+/// ```rust
+/// use highway::shard::HashRing;
+/// use highway::Key;
+///
+/// let mut ring = HashRing::new(Key([1, 2, 3, 4]), 100);
+/// ring.add("node-a");
+/// ring.add("node-b");
+/// let node = ring.get(b"user-42").unwrap();
+/// assert!(*node == "node-a" || *node == "node-b");
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct HashRing<N> {
+    key: PreparedKey,
+    replicas: u32,
+    nodes: Vec<N>,
+    points: Vec<(u64, usize)>,
+}
+
+#[cfg(feature = "alloc")]
+impl<N: AsRef<[u8]>> HashRing<N> {
+    /// Creates an empty ring that places `replicas` virtual nodes per node
+    #[must_use]
+    pub fn new(key: Key, replicas: u32) -> Self {
+        HashRing {
+            key: PreparedKey::new(key),
+            replicas,
+            nodes: Vec::new(),
+            points: Vec::new(),
+        }
+    }
+
+    /// The number of nodes on the ring
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns true if the ring has no nodes
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// The nodes on the ring, in insertion order
+    #[must_use]
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    /// Adds a node to the ring
+    pub fn add(&mut self, node: N) {
+        let index = self.nodes.len();
+        for replica in 0..self.replicas {
+            let point = node_hash(&self.key, node.as_ref(), &replica.to_le_bytes());
+            self.points.push((point, index));
+        }
+        self.nodes.push(node);
+        self.points.sort_unstable();
+    }
+
+    /// Removes the first node with the same bytes as `node`, returning it if it was present
+    pub fn remove(&mut self, node: &[u8]) -> Option<N> {
+        let index = self.nodes.iter().position(|n| n.as_ref() == node)?;
+        self.points.retain(|&(_, i)| i != index);
+        for (_, i) in &mut self.points {
+            if *i > index {
+                *i -= 1;
+            }
+        }
+        Some(self.nodes.remove(index))
+    }
+
+    /// Returns the node that `id` is assigned to, or `None` if the ring is empty
+    #[must_use]
+    pub fn get(&self, id: &[u8]) -> Option<&N> {
+        let hash = self.key.hash64(id);
+        let position = self.points.partition_point(|&(point, _)| point < hash);
+        let &(_, index) = self.points.get(position).or_else(|| self.points.first())?;
+        Some(&self.nodes[index])
+    }
+}
@@ -0,0 +1,458 @@
+use crate::internal::{HashPacket, PACKET_SIZE};
+use crate::key::Key;
+use crate::traits::{HighwayHash, HighwayHashReset, ResetError};
+use crate::PortableHash;
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref,
+    SubAssign,
+};
+use core::simd::{simd_swizzle, u64x4, u8x32, ToBytes};
+
+/// HighwayHash implementation written against the nightly portable SIMD API
+/// (`core::simd`).
+///
+/// This is intended for targets where the crate has no dedicated intrinsics
+/// (eg: riscv64, loongarch64, powerpc64le), so that LLVM can lower the 256bit
+/// vectors to whatever the target provides. Requires the `nightly-simd`
+/// feature and a nightly compiler.
+#[derive(Debug, Clone, Copy)]
+pub struct SimdHash {
+    v0: V4x64U,
+    v1: V4x64U,
+    mul0: V4x64U,
+    mul1: V4x64U,
+    buffer: HashPacket,
+    pub(crate) key: Option<Key>,
+}
+
+impl HighwayHash for SimdHash {
+    #[inline]
+    fn append(&mut self, data: &[u8]) {
+        self.append(data);
+    }
+
+    #[inline]
+    fn append_vectored<T: Deref<Target = [u8]>>(&mut self, fragments: &[T]) {
+        self.append_vectored(fragments);
+    }
+
+    #[inline]
+    fn finalize64(mut self) -> u64 {
+        Self::finalize64(&mut self)
+    }
+
+    #[inline]
+    fn finalize128(mut self) -> [u64; 2] {
+        Self::finalize128(&mut self)
+    }
+
+    #[inline]
+    fn finalize256(mut self) -> [u64; 4] {
+        Self::finalize256(&mut self)
+    }
+
+    #[inline]
+    fn checkpoint(&self) -> [u8; 164] {
+        PortableHash {
+            v0: self.v0.as_arr(),
+            v1: self.v1.as_arr(),
+            mul0: self.mul0.as_arr(),
+            mul1: self.mul1.as_arr(),
+            buffer: self.buffer,
+            key: self.key,
+        }
+        .checkpoint()
+    }
+}
+
+impl Default for SimdHash {
+    fn default() -> Self {
+        SimdHash::new(Key::default())
+    }
+}
+
+impl HighwayHashReset for SimdHash {
+    #[inline]
+    fn finalize64_reset(&mut self) -> Result<u64, ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        let hash = Self::finalize64(self);
+        self.rekey(key);
+        Ok(hash)
+    }
+
+    #[inline]
+    fn finalize128_reset(&mut self) -> Result<[u64; 2], ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        let hash = Self::finalize128(self);
+        self.rekey(key);
+        Ok(hash)
+    }
+
+    #[inline]
+    fn finalize256_reset(&mut self) -> Result<[u64; 4], ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        let hash = Self::finalize256(self);
+        self.rekey(key);
+        Ok(hash)
+    }
+
+    #[inline]
+    fn reset(&mut self) -> Result<(), ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        self.rekey(key);
+        Ok(())
+    }
+
+    #[inline]
+    fn rekey(&mut self, key: Key) {
+        *self = Self::new(key);
+    }
+}
+
+impl SimdHash {
+    /// Creates a new `SimdHash` from a `Key`
+    #[must_use]
+    pub fn new(key: Key) -> Self {
+        let mul0 = V4x64U::new(
+            0x243f_6a88_85a3_08d3,
+            0x1319_8a2e_0370_7344,
+            0xa409_3822_299f_31d0,
+            0xdbe6_d5d5_fe4c_ce2f,
+        );
+        let mul1 = V4x64U::new(
+            0x4528_21e6_38d0_1377,
+            0xbe54_66cf_34e9_0c6c,
+            0xc0ac_f169_b5f1_8a8c,
+            0x3bd3_9e10_cb0e_f593,
+        );
+
+        let lanes = V4x64U::from(u64x4::from_array(key.0));
+
+        SimdHash {
+            v0: lanes ^ mul0,
+            v1: lanes.rotate_by_32() ^ mul1,
+            mul0,
+            mul1,
+            buffer: HashPacket::default(),
+            key: Some(key),
+        }
+    }
+
+    /// Creates a new `SimdHash` from a checkpoint
+    #[must_use]
+    pub fn from_checkpoint(data: [u8; 164]) -> Self {
+        Self::from_portable(&PortableHash::from_checkpoint(data))
+    }
+
+    /// Creates a new `SimdHash` from a checkpoint and the key it was created with. A checkpoint does
+    /// not record the key, so supplying it here lets [`HighwayHashReset::reset`] restore the
+    /// keyed initial state.
+    #[must_use]
+    pub fn from_checkpoint_with_key(data: [u8; 164], key: Key) -> Self {
+        Self {
+            key: Some(key),
+            ..Self::from_checkpoint(data)
+        }
+    }
+
+    pub(crate) fn from_portable(portable: &PortableHash) -> Self {
+        SimdHash {
+            v0: V4x64U::from(u64x4::from_array(portable.v0)),
+            v1: V4x64U::from(u64x4::from_array(portable.v1)),
+            mul0: V4x64U::from(u64x4::from_array(portable.mul0)),
+            mul1: V4x64U::from(u64x4::from_array(portable.mul1)),
+            buffer: portable.buffer,
+            key: portable.key,
+        }
+    }
+
+    pub(crate) fn finalize64(&mut self) -> u64 {
+        if !self.buffer.is_empty() {
+            self.update_remainder();
+        }
+
+        for _i in 0..4 {
+            let permuted = SimdHash::permute(&self.v0);
+            self.update(permuted);
+        }
+
+        // Each lane is sufficiently mixed, so just truncate to 64 bits.
+        let hash = self.v0 + self.mul0 + self.v1 + self.mul1;
+        hash.0[0]
+    }
+
+    pub(crate) fn finalize128(&mut self) -> [u64; 2] {
+        if !self.buffer.is_empty() {
+            self.update_remainder();
+        }
+
+        for _i in 0..6 {
+            let permuted = SimdHash::permute(&self.v0);
+            self.update(permuted);
+        }
+
+        let sum0 = self.v0 + self.mul0;
+        let sum1 = self.v1 + self.mul1;
+        let hash = sum0 + V4x64U::from(simd_swizzle!(sum1.0, [2, 3, 0, 1]));
+        [hash.0[0], hash.0[1]]
+    }
+
+    pub(crate) fn finalize256(&mut self) -> [u64; 4] {
+        if !self.buffer.is_empty() {
+            self.update_remainder();
+        }
+
+        for _i in 0..10 {
+            let permuted = SimdHash::permute(&self.v0);
+            self.update(permuted);
+        }
+
+        let sum0 = self.v0 + self.mul0;
+        let sum1 = self.v1 + self.mul1;
+        SimdHash::modular_reduction(&sum1, &sum0).as_arr()
+    }
+
+    #[inline]
+    fn data_to_lanes(packet: &[u8]) -> V4x64U {
+        V4x64U::from(u64x4::from_le_bytes(u8x32::from_slice(packet)))
+    }
+
+    fn update_remainder(&mut self) {
+        let size = self.buffer.len() as u64;
+        self.v0 += V4x64U::splat((size << 32) + size);
+        self.v1 = self.v1.rotate_32_by(size);
+        let packet = PortableHash::remainder(self.buffer.inner(), self.buffer.len());
+        self.update(SimdHash::data_to_lanes(&packet));
+    }
+
+    fn zipper_merge(v: &V4x64U) -> V4x64U {
+        let bytes = v.0.to_le_bytes();
+        let merged = simd_swizzle!(
+            bytes,
+            [
+                3, 12, 2, 5, 14, 1, 15, 0, 11, 4, 10, 13, 9, 6, 8, 7, //
+                19, 28, 18, 21, 30, 17, 31, 16, 27, 20, 26, 29, 25, 22, 24, 23,
+            ]
+        );
+        V4x64U::from(u64x4::from_le_bytes(merged))
+    }
+
+    fn update(&mut self, packet: V4x64U) {
+        self.v1 += packet;
+        self.v1 += self.mul0;
+        self.mul0 ^= self.v1.mul_low32(&self.v0.shr_by_32());
+        self.v0 += self.mul1;
+        self.mul1 ^= self.v0.mul_low32(&self.v1.shr_by_32());
+        self.v0 += SimdHash::zipper_merge(&self.v1);
+        self.v1 += SimdHash::zipper_merge(&self.v0);
+    }
+
+    fn permute(v: &V4x64U) -> V4x64U {
+        V4x64U::from(simd_swizzle!(v.0, [2, 3, 0, 1])).rotate_by_32()
+    }
+
+    fn modular_reduction(x: &V4x64U, init: &V4x64U) -> V4x64U {
+        let zero = u64x4::splat(0);
+        let top_bits2 = V4x64U::from(simd_swizzle!(zero, x.0 >> 62, [0, 4, 2, 6]));
+        let top_bits1 = V4x64U::from(simd_swizzle!(zero, x.0 >> 63, [0, 4, 2, 6]));
+        let upper_bits = V4x64U::new(0xC000_0000_0000_0000, 0, 0xC000_0000_0000_0000, 0);
+        let masked = x.and_not(&upper_bits);
+        let shifted1 = masked + masked;
+        let shifted2 = shifted1 + shifted1;
+
+        *init ^ shifted2 ^ top_bits2 ^ shifted1 ^ top_bits1
+    }
+
+    fn append(&mut self, data: &[u8]) {
+        if self.buffer.is_empty() {
+            let mut chunks = data.chunks_exact(PACKET_SIZE);
+            for chunk in chunks.by_ref() {
+                self.update(Self::data_to_lanes(chunk));
+            }
+            self.buffer.set_to(chunks.remainder());
+        } else if let Some(tail) = self.buffer.fill(data) {
+            self.update(Self::data_to_lanes(self.buffer.inner()));
+            let mut chunks = tail.chunks_exact(PACKET_SIZE);
+            for chunk in chunks.by_ref() {
+                self.update(Self::data_to_lanes(chunk));
+            }
+
+            self.buffer.set_to(chunks.remainder());
+        }
+    }
+
+    fn append_vectored<T: Deref<Target = [u8]>>(&mut self, fragments: &[T]) {
+        let mut buffer = self.buffer;
+        buffer.assemble(fragments, |packet| self.update(Self::data_to_lanes(packet)));
+        self.buffer = buffer;
+    }
+}
+
+impl_write!(SimdHash);
+impl_hasher!(SimdHash);
+
+#[derive(Clone, Copy, Default)]
+pub struct V4x64U(pub u64x4);
+
+impl core::fmt::Debug for V4x64U {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "V4x64U: {:?}", self.as_arr())
+    }
+}
+
+impl V4x64U {
+    #[inline]
+    pub fn new(highest: u64, high: u64, low: u64, lowest: u64) -> Self {
+        V4x64U(u64x4::from_array([lowest, low, high, highest]))
+    }
+
+    #[inline]
+    pub fn splat(x: u64) -> Self {
+        V4x64U(u64x4::splat(x))
+    }
+
+    #[inline]
+    pub fn as_arr(&self) -> [u64; 4] {
+        self.0.to_array()
+    }
+
+    #[inline]
+    pub fn rotate_by_32(&self) -> Self {
+        V4x64U((self.0 << 32) | (self.0 >> 32))
+    }
+
+    #[inline]
+    pub fn rotate_32_by(&self, count: u64) -> Self {
+        let mask = u64x4::splat(0xffff_ffff);
+        let count = u64x4::splat(count);
+        let tip = u64x4::splat(32);
+        let low = self.0 & mask;
+        let high = self.0 >> 32;
+        let low = ((low << count) | (low >> (tip - count))) & mask;
+        let high = ((high << count) | (high >> (tip - count))) & mask;
+        V4x64U(low | (high << 32))
+    }
+
+    #[inline]
+    pub fn shr_by_32(&self) -> Self {
+        V4x64U(self.0 >> 32)
+    }
+
+    #[inline]
+    pub fn mul_low32(&self, x: &V4x64U) -> Self {
+        let mask = u64x4::splat(0xffff_ffff);
+        V4x64U((self.0 & mask) * (x.0 & mask))
+    }
+
+    #[inline]
+    pub fn and_not(&self, neg_mask: &V4x64U) -> Self {
+        V4x64U(self.0 & !neg_mask.0)
+    }
+}
+
+impl From<u64x4> for V4x64U {
+    #[inline]
+    fn from(v: u64x4) -> Self {
+        V4x64U(v)
+    }
+}
+
+impl AddAssign for V4x64U {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        self.0 += other.0;
+    }
+}
+
+impl SubAssign for V4x64U {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        self.0 -= other.0;
+    }
+}
+
+impl BitAndAssign for V4x64U {
+    #[inline]
+    fn bitand_assign(&mut self, other: Self) {
+        self.0 &= other.0;
+    }
+}
+
+impl BitAnd for V4x64U {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        V4x64U(self.0 & other.0)
+    }
+}
+
+impl BitOrAssign for V4x64U {
+    #[inline]
+    fn bitor_assign(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+}
+
+impl BitOr for V4x64U {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        V4x64U(self.0 | other.0)
+    }
+}
+
+impl BitXorAssign for V4x64U {
+    #[inline]
+    fn bitxor_assign(&mut self, other: Self) {
+        self.0 ^= other.0;
+    }
+}
+
+impl Add for V4x64U {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        V4x64U(self.0 + other.0)
+    }
+}
+
+impl BitXor for V4x64U {
+    type Output = Self;
+
+    #[inline]
+    fn bitxor(self, other: Self) -> Self {
+        V4x64U(self.0 ^ other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zipper_merge() {
+        let x = V4x64U::new(
+            0x0264_432C_CD8A_70E0,
+            0x0B28_E3EF_EBB3_172D,
+            0x0264_432C_CD8A_70E0,
+            0x0B28_E3EF_EBB3_172D,
+        );
+        let y = SimdHash::zipper_merge(&x);
+        assert_eq!(
+            y.as_arr(),
+            [
+                0x2D02_1764_E3B3_2CEB,
+                0x0BE0_2870_438A_EFCD,
+                0x2D02_1764_E3B3_2CEB,
+                0x0BE0_2870_438A_EFCD
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rotate_32_by() {
+        let x = V4x64U::splat(0x8000_0001_0000_0003);
+        assert_eq!(x.rotate_32_by(1).as_arr(), [0x0000_0003_0000_0006; 4]);
+    }
+}
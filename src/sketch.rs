@@ -0,0 +1,237 @@
+//! Similarity sketches for near-duplicate detection.
+//!
+//! [`MinHasher`] builds MinHash signatures whose agreement estimates the Jaccard similarity of
+//! two sets of shingles, and [`SimHasher`] builds 64bit SimHashes whose Hamming distance
+//! estimates the angle between two weighted feature vectors. Both are keyed with a [`Key`] and
+//! produce the same sketches on every backend.
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use highway::sketch::MinHasher;
+//! use highway::Key;
+//!
+//! let hasher = MinHasher::new(Key([1, 2, 3, 4]), 128);
+//! let a = hasher.signature("the quick brown fox jumps".split(' '));
+//! let b = hasher.signature("the quick brown dog jumps".split(' '));
+//! let similarity = a.jaccard(&b);
+//! assert!(similarity > 0.4 && similarity < 0.9);
+//! # }
+//! ```
+
+#[cfg(feature = "alloc")]
+use crate::builder::HighwayHasher;
+use crate::builder::PreparedKey;
+use crate::key::Key;
+#[cfg(feature = "alloc")]
+use crate::traits::HighwayHash;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+/// Computes MinHash signatures of a configurable length
+///
+/// A shingle is appended to the keyed hasher once, and the hasher is then cloned and finalized
+/// with a block counter to produce four minima candidates per `finalize256` call.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct MinHasher {
+    key: PreparedKey,
+    len: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl MinHasher {
+    /// Creates a hasher that produces signatures of `len` minima
+    #[must_use]
+    pub fn new(key: Key, len: usize) -> Self {
+        MinHasher {
+            key: PreparedKey::new(key),
+            len,
+        }
+    }
+
+    /// The key of the hasher
+    #[must_use]
+    pub fn key(&self) -> Key {
+        self.key.key()
+    }
+
+    /// The number of minima in a signature
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if signatures hold no minima
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the signature of the empty set, ready for [`MinHasher::insert`]
+    #[must_use]
+    pub fn empty_signature(&self) -> MinHashSignature {
+        MinHashSignature(vec![u64::MAX; self.len])
+    }
+
+    /// Folds a shingle into a signature
+    ///
+    /// # Panics
+    ///
+    /// Panics if the signature was created by a hasher of a different length
+    pub fn insert(&self, signature: &mut MinHashSignature, shingle: &[u8]) {
+        assert_eq!(signature.0.len(), self.len, "signature length mismatch");
+        let mut base: HighwayHasher = self.key.hasher();
+        base.append(shingle);
+
+        for (block, minima) in signature.0.chunks_mut(4).enumerate() {
+            let mut hasher = base;
+            hasher.append_u64_le(&[block as u64]);
+            let lanes = hasher.finalize256();
+            for (minimum, lane) in minima.iter_mut().zip(lanes.iter()) {
+                *minimum = (*minimum).min(*lane);
+            }
+        }
+    }
+
+    /// Returns the signature of a set of shingles
+    #[must_use]
+    pub fn signature<I>(&self, shingles: I) -> MinHashSignature
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        let mut signature = self.empty_signature();
+        for shingle in shingles {
+            self.insert(&mut signature, shingle.as_ref());
+        }
+        signature
+    }
+}
+
+/// A MinHash signature. See [`MinHasher`]
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MinHashSignature(Vec<u64>);
+
+#[cfg(feature = "alloc")]
+impl MinHashSignature {
+    /// The minima of the signature
+    #[must_use]
+    pub fn as_slice(&self) -> &[u64] {
+        &self.0
+    }
+
+    /// Estimates the Jaccard similarity of the two sets as the fraction of agreeing minima
+    ///
+    /// # Panics
+    ///
+    /// Panics if the signatures have different lengths
+    #[must_use]
+    pub fn jaccard(&self, other: &MinHashSignature) -> f64 {
+        assert_eq!(self.0.len(), other.0.len(), "signature length mismatch");
+        if self.0.is_empty() {
+            return 0.0;
+        }
+
+        let equal = self.0.iter().zip(&other.0).filter(|(a, b)| a == b).count();
+        equal as f64 / self.0.len() as f64
+    }
+
+    /// Adds the shingles of another signature, as if they had been inserted into this one
+    ///
+    /// # Panics
+    ///
+    /// Panics if the signatures have different lengths
+    pub fn merge(&mut self, other: &MinHashSignature) {
+        assert_eq!(self.0.len(), other.0.len(), "signature length mismatch");
+        for (a, b) in self.0.iter_mut().zip(&other.0) {
+            *a = (*a).min(*b);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<MinHashSignature> for Vec<u64> {
+    fn from(signature: MinHashSignature) -> Self {
+        signature.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<Vec<u64>> for MinHashSignature {
+    fn from(minima: Vec<u64>) -> Self {
+        MinHashSignature(minima)
+    }
+}
+
+/// Computes 64bit SimHashes of weighted features
+#[derive(Debug, Clone)]
+pub struct SimHasher {
+    key: PreparedKey,
+}
+
+impl SimHasher {
+    /// Creates a hasher keyed with `key`
+    #[must_use]
+    pub fn new(key: Key) -> Self {
+        SimHasher {
+            key: PreparedKey::new(key),
+        }
+    }
+
+    /// The key of the hasher
+    #[must_use]
+    pub fn key(&self) -> Key {
+        self.key.key()
+    }
+
+    /// Returns the SimHash of features that all have a weight of one
+    #[must_use]
+    pub fn hash<I>(&self, features: I) -> u64
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        self.hash_weighted(features.into_iter().map(|feature| (feature, 1)))
+    }
+
+    /// Returns the SimHash of weighted features. Each bit is set when the summed weight of the
+    /// features whose hash has that bit set outweighs the rest.
+    #[must_use]
+    pub fn hash_weighted<I, F>(&self, features: I) -> u64
+    where
+        I: IntoIterator<Item = (F, i64)>,
+        F: AsRef<[u8]>,
+    {
+        let mut counts = [0i64; 64];
+        for (feature, weight) in features {
+            let hash = self.key.hash64(feature.as_ref());
+            for (bit, count) in counts.iter_mut().enumerate() {
+                if hash & (1 << bit) != 0 {
+                    *count = count.wrapping_add(weight);
+                } else {
+                    *count = count.wrapping_sub(weight);
+                }
+            }
+        }
+
+        counts
+            .iter()
+            .enumerate()
+            .filter(|(_, &count)| count > 0)
+            .fold(0, |acc, (bit, _)| acc | (1 << bit))
+    }
+}
+
+/// Returns the number of differing bits between two SimHashes
+#[must_use]
+pub fn hamming_distance(a: u64, b: u64) -> u32 {
+    (a ^ b).count_ones()
+}
+
+/// Estimates the similarity of two SimHashes as the fraction of agreeing bits
+#[must_use]
+pub fn hamming_similarity(a: u64, b: u64) -> f64 {
+    1.0 - f64::from(hamming_distance(a, b)) / 64.0
+}
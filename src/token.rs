@@ -0,0 +1,261 @@
+//! Signed, expiring tokens for session cookies, CSRF tokens, and other short-lived messages.
+//!
+//! A token carries a payload, the time it was issued, and the time it expires, authenticated by
+//! a keyed 128bit HighwayHash tag, and is encoded as unpadded URL-safe base64 so it can be used
+//! in cookies and URLs as is. The payload is signed, not encrypted: anyone holding a token can
+//! read it.
+//!
+//! The tag covers a fixed label, the signer's domain (eg: `"session"` or `"csrf"`), and every
+//! other byte of the token, so a token issued for one domain is rejected by the others.
+//! HighwayHash is a keyed hash rather than a cryptographic MAC; it is suited to authenticating
+//! short-lived messages when the key is kept secret and rotated.
+//!
+//! ```rust
+//! use highway::token::{TokenError, TokenSigner};
+//! use highway::Key;
+//!
+//! let signer = TokenSigner::new(Key([1, 2, 3, 4]), "session");
+//! let token = signer.sign(b"user=42", 1_000, 1_600);
+//!
+//! let verified = signer.verify(&token, 1_200).unwrap();
+//! assert_eq!(verified.payload(), b"user=42");
+//! assert_eq!(signer.verify(&token, 1_600), Err(TokenError::Expired));
+//! assert_eq!(signer.verify(&token, 900), Err(TokenError::NotYetValid));
+//!
+//! let csrf = TokenSigner::new(Key([1, 2, 3, 4]), "csrf");
+//! assert_eq!(csrf.verify(&token, 1_200), Err(TokenError::InvalidTag));
+//! ```
+
+use crate::builder::{HighwayHasher, PreparedKey};
+use crate::internal::{append_label, append_length_prefixed, constant_eq};
+use crate::key::Key;
+use crate::traits::HighwayHash;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Label that starts every tag, see `append_label`
+const LABEL: &[u8] = b"highway-token";
+
+/// Format version, the first byte of every token
+const VERSION: u8 = 1;
+
+/// Version, issued at, and expiry
+const HEADER_SIZE: usize = 1 + 8 + 8;
+
+const TAG_SIZE: usize = 16;
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Reasons a token fails verification
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenError {
+    /// The token is not valid base64 or is too short
+    Malformed,
+    /// The token was not signed by any of the keys for this domain
+    InvalidTag,
+    /// The token was authentic but has expired
+    Expired,
+    /// The token was authentic but was issued after the time it was verified at
+    NotYetValid,
+}
+
+impl fmt::Display for TokenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenError::Malformed => f.write_str("malformed token"),
+            TokenError::InvalidTag => f.write_str("invalid token signature"),
+            TokenError::Expired => f.write_str("token has expired"),
+            TokenError::NotYetValid => f.write_str("token is not yet valid"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TokenError {}
+
+/// The contents of a token that passed verification
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifiedToken {
+    payload: Vec<u8>,
+    issued_at: u64,
+    expires_at: u64,
+}
+
+impl VerifiedToken {
+    /// The signed payload
+    #[must_use]
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
+    }
+
+    /// When the token was issued, in the units of the clock given to `sign`
+    #[must_use]
+    pub fn issued_at(&self) -> u64 {
+        self.issued_at
+    }
+
+    /// When the token expires, in the units of the clock given to `sign`
+    #[must_use]
+    pub fn expires_at(&self) -> u64 {
+        self.expires_at
+    }
+
+    /// Consumes the token, returning the payload
+    #[must_use]
+    pub fn into_payload(self) -> Vec<u8> {
+        self.payload
+    }
+}
+
+/// Signs and verifies tokens for a single domain
+///
+/// Tokens are signed with the current key. Verification also accepts tokens signed with
+/// previous keys, so keys can be rotated without invalidating live sessions.
+#[derive(Debug, Clone)]
+pub struct TokenSigner {
+    keys: Vec<PreparedKey>,
+    domain: String,
+}
+
+impl TokenSigner {
+    /// Creates a signer for tokens of the given domain
+    #[must_use]
+    pub fn new(key: Key, domain: &str) -> Self {
+        TokenSigner {
+            keys: alloc::vec![PreparedKey::new(key)],
+            domain: String::from(domain),
+        }
+    }
+
+    /// Accepts tokens signed with `key` in addition to the current key
+    #[must_use]
+    pub fn with_previous_key(mut self, key: Key) -> Self {
+        self.keys.push(PreparedKey::new(key));
+        self
+    }
+
+    /// Signs new tokens with `key`, still accepting tokens signed with the keys used so far
+    pub fn rotate(&mut self, key: Key) {
+        self.keys.insert(0, PreparedKey::new(key));
+    }
+
+    /// Stops accepting tokens signed with any but the `keep` most recent keys
+    pub fn retain_keys(&mut self, keep: usize) {
+        self.keys.truncate(keep.max(1));
+    }
+
+    /// The domain tokens are signed for
+    #[must_use]
+    pub fn domain(&self) -> &str {
+        &self.domain
+    }
+
+    fn tag(&self, key: &PreparedKey, body: &[u8]) -> [u8; TAG_SIZE] {
+        let mut hasher: HighwayHasher = key.hasher();
+        append_label(&mut hasher, LABEL);
+        append_length_prefixed(&mut hasher, self.domain.as_bytes());
+        hasher.append(body);
+        let [low, high] = hasher.finalize128();
+
+        let mut tag = [0u8; TAG_SIZE];
+        tag[..8].copy_from_slice(&low.to_le_bytes());
+        tag[8..].copy_from_slice(&high.to_le_bytes());
+        tag
+    }
+
+    /// Returns a token for `payload` that is valid from `issued_at` until `expires_at`. Any
+    /// clock can be used (eg: unix seconds) as long as `verify` is given the same one.
+    #[must_use]
+    pub fn sign(&self, payload: &[u8], issued_at: u64, expires_at: u64) -> String {
+        let mut body = Vec::with_capacity(HEADER_SIZE + payload.len() + TAG_SIZE);
+        body.push(VERSION);
+        body.extend_from_slice(&issued_at.to_le_bytes());
+        body.extend_from_slice(&expires_at.to_le_bytes());
+        body.extend_from_slice(payload);
+
+        let tag = self.tag(&self.keys[0], &body);
+        body.extend_from_slice(&tag);
+        encode(&body)
+    }
+
+    /// Checks that the token was signed for this domain by one of the keys, comparing tags in
+    /// constant time, and that `now` falls between its issue and expiry times
+    pub fn verify(&self, token: &str, now: u64) -> Result<VerifiedToken, TokenError> {
+        let data = decode(token).ok_or(TokenError::Malformed)?;
+        if data.len() < HEADER_SIZE + TAG_SIZE || data[0] != VERSION {
+            return Err(TokenError::Malformed);
+        }
+
+        let (body, tag) = data.split_at(data.len() - TAG_SIZE);
+        let authentic = self.keys.iter().fold(false, |found, key| {
+            found | constant_eq(&self.tag(key, body), tag)
+        });
+        if !authentic {
+            return Err(TokenError::InvalidTag);
+        }
+
+        let word = |i: usize| {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(&body[i..i + 8]);
+            u64::from_le_bytes(bytes)
+        };
+        let issued_at = word(1);
+        let expires_at = word(9);
+        if now < issued_at {
+            return Err(TokenError::NotYetValid);
+        }
+
+        if now >= expires_at {
+            return Err(TokenError::Expired);
+        }
+
+        Ok(VerifiedToken {
+            payload: body[HEADER_SIZE..].to_vec(),
+            issued_at,
+            expires_at,
+        })
+    }
+}
+
+/// Encodes bytes as unpadded URL-safe base64
+fn encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len() / 3 * 4 + 3);
+    for chunk in data.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        for i in 0..=chunk.len() {
+            out.push(char::from(BASE64[((n >> (18 - 6 * i)) & 0x3f) as usize]));
+        }
+    }
+    out
+}
+
+/// Decodes unpadded URL-safe base64, rejecting non-canonical encodings
+fn decode(data: &str) -> Option<Vec<u8>> {
+    let data = data.as_bytes();
+    if data.len() % 4 == 1 {
+        return None;
+    }
+
+    let mut out = Vec::with_capacity(data.len() * 3 / 4);
+    for chunk in data.chunks(4) {
+        let mut n = 0u32;
+        for (i, &c) in chunk.iter().enumerate() {
+            let value = BASE64.iter().position(|&b| b == c)? as u32;
+            n |= value << (18 - 6 * i);
+        }
+
+        let bytes = [(n >> 16) as u8, (n >> 8) as u8, n as u8];
+        let len = chunk.len() - 1;
+        if bytes[len..].iter().any(|&b| b != 0) {
+            return None;
+        }
+        out.extend_from_slice(&bytes[..len]);
+    }
+    Some(out)
+}
@@ -0,0 +1,47 @@
+//! Integration with tokio's asynchronous I/O.
+//!
+//! With the `tokio` feature enabled, every hasher implements `tokio::io::AsyncWrite`, so that
+//! data can be hashed with `tokio::io::copy`. This module contains helpers for hashing an
+//! `AsyncRead` to completion.
+
+use crate::async_read::{self, PollRead};
+use crate::Key;
+use ::tokio::io::{AsyncRead, ReadBuf};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::io;
+
+/// Reads until EOF and returns the 256bit hash of everything read.
+///
+/// ```rust
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() -> std::io::Result<()> {
+/// use highway::{HighwayHash, HighwayHasher, Key};
+/// let data = b"hello world";
+/// let hash = highway::tokio::hash_async_reader(&data[..], Key::default()).await?;
+/// assert_eq!(hash, HighwayHasher::default().hash256(data));
+/// # Ok(())
+/// # }
+/// ```
+pub async fn hash_async_reader<R>(reader: R, key: Key) -> io::Result<[u64; 4]>
+where
+    R: AsyncRead + Unpin,
+{
+    async_read::hash_reader(TokioRead(reader), key).await
+}
+
+struct TokioRead<R>(R);
+
+impl<R> PollRead for TokioRead<R>
+where
+    R: AsyncRead + Unpin,
+{
+    fn poll_read_into(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        let mut buf = ReadBuf::new(buf);
+        match Pin::new(&mut self.0).poll_read(cx, &mut buf) {
+            Poll::Ready(Ok(())) => Poll::Ready(Ok(buf.filled().len())),
+            Poll::Ready(Err(e)) => Poll::Ready(Err(e)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
@@ -1,3 +1,10 @@
+use crate::key::Key;
+use crate::xof::XofReader;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::fmt;
+use core::ops::Deref;
+
 /// The common set of methods for hashing data.
 pub trait HighwayHash: Sized {
     /// Convenience function for hashing all data in a single call and receiving a 64bit hash.
@@ -21,12 +28,72 @@ pub trait HighwayHash: Sized {
         self.finalize256()
     }
 
+    /// Convenience function for hashing data split across several fragments in a single call
+    /// and receiving a 64bit hash. Results are equivalent to hashing the concatenation of the
+    /// fragments.
+    fn hash64_fragments<T: Deref<Target = [u8]>>(mut self, fragments: &[T]) -> u64 {
+        self.append_vectored(fragments);
+        self.finalize64()
+    }
+
+    /// Convenience function for hashing data split across several fragments in a single call
+    /// and receiving a 128bit hash. Results are equivalent to hashing the concatenation of the
+    /// fragments.
+    fn hash128_fragments<T: Deref<Target = [u8]>>(mut self, fragments: &[T]) -> [u64; 2] {
+        self.append_vectored(fragments);
+        self.finalize128()
+    }
+
+    /// Convenience function for hashing data split across several fragments in a single call
+    /// and receiving a 256bit hash. Results are equivalent to hashing the concatenation of the
+    /// fragments.
+    fn hash256_fragments<T: Deref<Target = [u8]>>(mut self, fragments: &[T]) -> [u64; 4] {
+        self.append_vectored(fragments);
+        self.finalize256()
+    }
+
     /// Adds data to be hashed. If it is important, the performance characteristics of this
     /// function differs depending on the amount of data previously hashed and the amount of
     /// data to be hashed. For instance, if one appends 50, 1 byte slices then appending the 32nd
     /// byte will have a performance outlier as the internal 32 byte block is complete and internally processed.
     fn append(&mut self, data: &[u8]);
 
+    /// Adds data split across several fragments (eg: `&[u8]`, `Vec<u8>`, or `std::io::IoSlice`)
+    /// to be hashed, as if the fragments had been concatenated and passed to `append`.
+    ///
+    /// The hashers in this crate assemble internal 32 byte blocks across fragment boundaries in
+    /// a single pass: only the bytes of a block straddling a boundary are buffered, the rest is
+    /// hashed in place. The default implementation appends each fragment in turn.
+    fn append_vectored<T: Deref<Target = [u8]>>(&mut self, fragments: &[T]) {
+        for fragment in fragments {
+            self.append(fragment);
+        }
+    }
+
+    /// Adds a slice of `u32` to be hashed. The hash is the same as appending the little endian
+    /// bytes of each integer, regardless of the host's endianness.
+    fn append_u32_le(&mut self, data: &[u32]) {
+        crate::internal::append_le(self, data, |x| x.to_le_bytes());
+    }
+
+    /// Adds a slice of `u64` to be hashed. The hash is the same as appending the little endian
+    /// bytes of each integer, regardless of the host's endianness.
+    fn append_u64_le(&mut self, data: &[u64]) {
+        crate::internal::append_le(self, data, |x| x.to_le_bytes());
+    }
+
+    /// Adds a slice of `f32` to be hashed. The hash is the same as appending the little endian
+    /// bytes of each float's bits (see [`f32::to_bits`]), regardless of the host's endianness.
+    fn append_f32_le(&mut self, data: &[f32]) {
+        crate::internal::append_le(self, data, |x| x.to_bits().to_le_bytes());
+    }
+
+    /// Adds a slice of `f64` to be hashed. The hash is the same as appending the little endian
+    /// bytes of each float's bits (see [`f64::to_bits`]), regardless of the host's endianness.
+    fn append_f64_le(&mut self, data: &[f64]) {
+        crate::internal::append_le(self, data, |x| x.to_bits().to_le_bytes());
+    }
+
     /// Consumes the hasher to return the 64bit hash
     fn finalize64(self) -> u64;
 
@@ -41,4 +108,178 @@ pub trait HighwayHash: Sized {
     /// Note: At this time, the checkpoint format and API should be considered experimental.
     /// The format may change in future versions.
     fn checkpoint(&self) -> [u8; 164];
+
+    /// Consumes the hasher to return a reader of arbitrarily long keyed pseudorandom output.
+    ///
+    /// This extendable-output mode is specific to this crate and not part of the HighwayHash
+    /// specification: no other implementation will produce the same output. The first 32 bytes
+    /// are the little endian bytes of `finalize256`, and the output is identical regardless of
+    /// backend. See [`XofReader`](crate::XofReader).
+    fn finalize_xof(self) -> XofReader {
+        XofReader::new(self.checkpoint())
+    }
+}
+
+/// Reuse of a hasher for one message after another, without rebuilding it or redoing backend
+/// selection.
+///
+/// Kept apart from [`HighwayHash`] so that implementing `HighwayHash` outside of this crate does
+/// not require these methods. Every hasher in this crate implements both traits.
+///
+/// ```rust
+/// use highway::{HighwayHash, HighwayHashReset, HighwayHasher, Key};
+/// let key = Key([1, 2, 3, 4]);
+/// let mut hasher = HighwayHasher::new(key);
+/// hasher.append(b"hello");
+/// assert_eq!(hasher.finalize64_reset(), Ok(HighwayHasher::new(key).hash64(b"hello")));
+/// hasher.append(b"world");
+/// assert_eq!(hasher.finalize64_reset(), Ok(HighwayHasher::new(key).hash64(b"world")));
+/// ```
+///
+/// A checkpoint does not record the key, so a hasher restored with `from_checkpoint` has no
+/// initial state to return to, and resetting it fails with [`ResetError`], leaving it untouched.
+/// Restore with `from_checkpoint_with_key`, or [`rekey`](Self::rekey) the hasher, to make it
+/// resettable.
+pub trait HighwayHashReset: HighwayHash {
+    /// Returns the 64bit hash and resets the hasher to its initial keyed state, ready to hash
+    /// the next message
+    fn finalize64_reset(&mut self) -> Result<u64, ResetError>;
+
+    /// Returns the 128bit hash and resets the hasher to its initial keyed state, ready to hash
+    /// the next message
+    fn finalize128_reset(&mut self) -> Result<[u64; 2], ResetError>;
+
+    /// Returns the 256bit hash and resets the hasher to its initial keyed state, ready to hash
+    /// the next message
+    fn finalize256_reset(&mut self) -> Result<[u64; 4], ResetError>;
+
+    /// Discards any appended data and returns the hasher to the initial state for its key
+    fn reset(&mut self) -> Result<(), ResetError>;
+
+    /// Replaces the key and returns the hasher to the initial state for the new key
+    fn rekey(&mut self, key: Key);
+}
+
+/// Error returned when resetting a hasher that was restored from a checkpoint without its key.
+/// See [`HighwayHashReset`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResetError(pub(crate) ());
+
+impl fmt::Display for ResetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("hasher was restored from a checkpoint without its key")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ResetError {}
+
+/// An object safe counterpart to [`HighwayHash`] so that hashers can be stored as trait objects
+/// (eg: `Box<dyn DynHighwayHash>`) and the implementation chosen at runtime.
+///
+/// Since a trait object can't be consumed, the finalizers take `&mut self`, and return the hash
+/// and reset the hasher to its initial keyed state, like [`HighwayHashReset::finalize64_reset`].
+/// They fail in the same way too, for a hasher restored from a checkpoint without its key.
+///
+/// The methods carry a `dyn_` prefix so that they don't collide with the [`HighwayHash`]
+/// methods of the same name when both traits are in scope.
+///
+/// Every [`HighwayHashReset`] implements this trait.
+///
+/// ```rust
+/// use highway::{DynHighwayHash, HighwayHash, Key, PortableHash};
+/// let key = Key([1, 2, 3, 4]);
+/// let mut hasher: Box<dyn DynHighwayHash> = Box::new(PortableHash::new(key));
+/// hasher.dyn_append(b"hello");
+/// assert_eq!(hasher.dyn_finalize64(), Ok(PortableHash::new(key).hash64(b"hello")));
+/// ```
+pub trait DynHighwayHash {
+    /// Adds data to be hashed. See [`HighwayHash::append`]
+    fn dyn_append(&mut self, data: &[u8]);
+
+    /// Returns the 64bit hash and resets the hasher. See [`HighwayHashReset::finalize64_reset`]
+    fn dyn_finalize64(&mut self) -> Result<u64, ResetError>;
+
+    /// Returns the 128bit hash and resets the hasher. See
+    /// [`HighwayHashReset::finalize128_reset`]
+    fn dyn_finalize128(&mut self) -> Result<[u64; 2], ResetError>;
+
+    /// Returns the 256bit hash and resets the hasher. See
+    /// [`HighwayHashReset::finalize256_reset`]
+    fn dyn_finalize256(&mut self) -> Result<[u64; 4], ResetError>;
+
+    /// Serialize the hasher state. See [`HighwayHash::checkpoint`]
+    fn dyn_checkpoint(&self) -> [u8; 164];
+
+    /// Returns a reader of extendable output and resets the hasher. See
+    /// [`HighwayHash::finalize_xof`]
+    fn dyn_finalize_xof(&mut self) -> Result<XofReader, ResetError>;
+
+    /// Discards any appended data. See [`HighwayHashReset::reset`]
+    fn dyn_reset(&mut self) -> Result<(), ResetError>;
+
+    /// Replaces the key and resets the hasher. See [`HighwayHashReset::rekey`]
+    fn dyn_rekey(&mut self, key: Key);
+
+    /// Clones the hasher, including any appended data, into a new trait object
+    #[cfg(feature = "alloc")]
+    fn box_clone(&self) -> Box<dyn DynHighwayHash>;
+}
+
+impl<H> DynHighwayHash for H
+where
+    H: HighwayHashReset + Clone + 'static,
+{
+    #[inline]
+    fn dyn_append(&mut self, data: &[u8]) {
+        HighwayHash::append(self, data);
+    }
+
+    #[inline]
+    fn dyn_finalize64(&mut self) -> Result<u64, ResetError> {
+        self.finalize64_reset()
+    }
+
+    #[inline]
+    fn dyn_finalize128(&mut self) -> Result<[u64; 2], ResetError> {
+        self.finalize128_reset()
+    }
+
+    #[inline]
+    fn dyn_finalize256(&mut self) -> Result<[u64; 4], ResetError> {
+        self.finalize256_reset()
+    }
+
+    #[inline]
+    fn dyn_checkpoint(&self) -> [u8; 164] {
+        HighwayHash::checkpoint(self)
+    }
+
+    fn dyn_finalize_xof(&mut self) -> Result<XofReader, ResetError> {
+        let reader = XofReader::new(HighwayHash::checkpoint(self));
+        HighwayHashReset::reset(self)?;
+        Ok(reader)
+    }
+
+    #[inline]
+    fn dyn_reset(&mut self) -> Result<(), ResetError> {
+        HighwayHashReset::reset(self)
+    }
+
+    #[inline]
+    fn dyn_rekey(&mut self, key: Key) {
+        HighwayHashReset::rekey(self, key);
+    }
+
+    #[cfg(feature = "alloc")]
+    fn box_clone(&self) -> Box<dyn DynHighwayHash> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Clone for Box<dyn DynHighwayHash> {
+    fn clone(&self) -> Self {
+        self.box_clone()
+    }
 }
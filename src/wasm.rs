@@ -1,13 +1,13 @@
 use crate::internal::{unordered_load3, HashPacket, PACKET_SIZE};
-use crate::{HighwayHash, Key, PortableHash};
+use crate::{HighwayHash, HighwayHashReset, Key, PortableHash, ResetError};
 use core::arch::wasm32::{self, v128};
 use core::ops::{
-    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, ShlAssign,
-    ShrAssign, SubAssign,
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref,
+    ShlAssign, ShrAssign, SubAssign,
 };
 
 /// HighwayHash powered by Wasm SIMD instructions
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct WasmHash {
     v0L: V2x64U,
     v0H: V2x64U,
@@ -18,6 +18,7 @@ pub struct WasmHash {
     mul1L: V2x64U,
     mul1H: V2x64U,
     buffer: HashPacket,
+    pub(crate) key: Option<Key>,
 }
 
 impl HighwayHash for WasmHash {
@@ -26,6 +27,11 @@ impl HighwayHash for WasmHash {
         self.append(data);
     }
 
+    #[inline]
+    fn append_vectored<T: Deref<Target = [u8]>>(&mut self, fragments: &[T]) {
+        self.append_vectored(fragments);
+    }
+
     #[inline]
     fn finalize64(mut self) -> u64 {
         Self::finalize64(&mut self)
@@ -65,11 +71,56 @@ impl HighwayHash for WasmHash {
             mul0,
             mul1,
             buffer: self.buffer,
+            key: self.key,
         }
         .checkpoint()
     }
 }
 
+impl Default for WasmHash {
+    fn default() -> Self {
+        WasmHash::new(Key::default())
+    }
+}
+
+impl HighwayHashReset for WasmHash {
+    #[inline]
+    fn finalize64_reset(&mut self) -> Result<u64, ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        let hash = Self::finalize64(self);
+        self.rekey(key);
+        Ok(hash)
+    }
+
+    #[inline]
+    fn finalize128_reset(&mut self) -> Result<[u64; 2], ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        let hash = Self::finalize128(self);
+        self.rekey(key);
+        Ok(hash)
+    }
+
+    #[inline]
+    fn finalize256_reset(&mut self) -> Result<[u64; 4], ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        let hash = Self::finalize256(self);
+        self.rekey(key);
+        Ok(hash)
+    }
+
+    #[inline]
+    fn reset(&mut self) -> Result<(), ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        self.rekey(key);
+        Ok(())
+    }
+
+    #[inline]
+    fn rekey(&mut self, key: Key) {
+        *self = Self::new(key);
+    }
+}
+
 impl WasmHash {
     /// Creates a new `WasmHash` based on Wasm SIMD extension
     #[must_use]
@@ -91,13 +142,28 @@ impl WasmHash {
             mul1L: init1L,
             mul1H: init1H,
             buffer: HashPacket::default(),
+            key: Some(key),
         }
     }
 
     /// Creates a new `NeonHash` from a checkpoint
     #[must_use]
     pub fn from_checkpoint(data: [u8; 164]) -> Self {
-        let portable = PortableHash::from_checkpoint(data);
+        Self::from_portable(&PortableHash::from_checkpoint(data))
+    }
+
+    /// Creates a new `WasmHash` from a checkpoint and the key it was created with. A checkpoint does
+    /// not record the key, so supplying it here lets [`HighwayHashReset::reset`] restore the
+    /// keyed initial state.
+    #[must_use]
+    pub fn from_checkpoint_with_key(data: [u8; 164], key: Key) -> Self {
+        Self {
+            key: Some(key),
+            ..Self::from_checkpoint(data)
+        }
+    }
+
+    pub(crate) fn from_portable(portable: &PortableHash) -> Self {
         WasmHash {
             v0L: V2x64U::new(portable.v0[1], portable.v0[0]),
             v0H: V2x64U::new(portable.v0[3], portable.v0[2]),
@@ -108,6 +174,7 @@ impl WasmHash {
             mul1L: V2x64U::new(portable.mul1[1], portable.mul1[0]),
             mul1H: V2x64U::new(portable.mul1[3], portable.mul1[2]),
             buffer: portable.buffer,
+            key: portable.key,
         }
     }
 
@@ -318,6 +385,12 @@ impl WasmHash {
             self.buffer.set_to(chunks.remainder());
         }
     }
+
+    fn append_vectored<T: Deref<Target = [u8]>>(&mut self, fragments: &[T]) {
+        let mut buffer = self.buffer;
+        buffer.assemble(fragments, |packet| self.update(Self::data_to_lanes(packet)));
+        self.buffer = buffer;
+    }
 }
 
 impl_write!(WasmHash);
@@ -560,14 +633,14 @@ pub mod tests {
     #[wasm_bindgen_test]
     fn test_add() {
         let x = V2x64U::new(55, 1);
-        let y = V2x64U::new(0x0264_432C_CD8A_70E0, 0x0B28E_3EFE_BB3_172D);
+        let y = V2x64U::new(0x0264_432C_CD8A_70E0, 0x0B28_E3EF_EBB3_172D);
         let z = x + y;
-        assert_eq!(z.as_arr(), [0x0B28_E3EF_EBB3_172E, 0x2644_32CC_D8A7_117]);
+        assert_eq!(z.as_arr(), [0x0B28_E3EF_EBB3_172E, 0x0264_432C_CD8A_7117]);
     }
 
     #[wasm_bindgen_test]
     fn test_mm_srli_epi64() {
-        let x = V2x64U::new(0x0264_432C_CD8A_70E0, 0x0B28E_3EFE_BB3_172D);
+        let x = V2x64U::new(0x0264_432C_CD8A_70E0, 0x0B28_E3EF_EBB3_172D);
         let y = V2x64U::from(_mm_srli_epi64(x.0, 33));
         assert_eq!(y.as_arr(), [0x0000_0000_0594_71F7, 0x0000_0000_0132_2196]);
     }
@@ -3,19 +3,24 @@ use super::{v2x64u::V2x64U, v4x64u::V4x64U};
 use crate::internal::unordered_load3;
 use crate::internal::{HashPacket, PACKET_SIZE};
 use crate::key::Key;
-use crate::traits::HighwayHash;
+use crate::traits::{HighwayHash, HighwayHashReset, ResetError};
 use crate::PortableHash;
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
 use core::arch::x86_64::*;
+use core::ops::Deref;
 
-/// AVX empowered implementation that will only work on `x86_64` with avx2 enabled at the CPU
-/// level.
-#[derive(Debug, Default, Clone)]
+/// AVX empowered implementation that will only work on `x86` and `x86_64` with avx2 enabled at
+/// the CPU level.
+#[derive(Debug, Clone, Copy)]
 pub struct AvxHash {
     v0: V4x64U,
     v1: V4x64U,
     mul0: V4x64U,
     mul1: V4x64U,
     buffer: HashPacket,
+    pub(crate) key: Option<Key>,
 }
 
 impl HighwayHash for AvxHash {
@@ -26,6 +31,13 @@ impl HighwayHash for AvxHash {
         }
     }
 
+    #[inline]
+    fn append_vectored<T: Deref<Target = [u8]>>(&mut self, fragments: &[T]) {
+        unsafe {
+            self.append_vectored(fragments);
+        }
+    }
+
     #[inline]
     fn finalize64(mut self) -> u64 {
         unsafe { Self::finalize64(&mut self) }
@@ -49,11 +61,57 @@ impl HighwayHash for AvxHash {
             mul0: unsafe { self.mul0.as_arr() },
             mul1: unsafe { self.mul1.as_arr() },
             buffer: self.buffer,
+            key: self.key,
         }
         .checkpoint()
     }
 }
 
+impl Default for AvxHash {
+    fn default() -> Self {
+        // Only sound where avx2 is available, as was the zeroed state this replaced
+        unsafe { AvxHash::force_new(Key::default()) }
+    }
+}
+
+impl HighwayHashReset for AvxHash {
+    #[inline]
+    fn finalize64_reset(&mut self) -> Result<u64, ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        let hash = unsafe { Self::finalize64(self) };
+        self.rekey(key);
+        Ok(hash)
+    }
+
+    #[inline]
+    fn finalize128_reset(&mut self) -> Result<[u64; 2], ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        let hash = unsafe { Self::finalize128(self) };
+        self.rekey(key);
+        Ok(hash)
+    }
+
+    #[inline]
+    fn finalize256_reset(&mut self) -> Result<[u64; 4], ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        let hash = unsafe { Self::finalize256(self) };
+        self.rekey(key);
+        Ok(hash)
+    }
+
+    #[inline]
+    fn reset(&mut self) -> Result<(), ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        self.rekey(key);
+        Ok(())
+    }
+
+    #[inline]
+    fn rekey(&mut self, key: Key) {
+        *self = unsafe { Self::force_new(key) };
+    }
+}
+
 impl AvxHash {
     /// Creates a new `AvxHash` while circumventing the runtime check for avx2.
     ///
@@ -78,14 +136,15 @@ impl AvxHash {
             0x3bd3_9e10_cb0e_f593,
         );
 
-        let key = V4x64U::from(_mm256_load_si256(key.0.as_ptr().cast::<__m256i>()));
+        let lanes = V4x64U::from(_mm256_load_si256(key.0.as_ptr().cast::<__m256i>()));
 
         AvxHash {
-            v0: key ^ mul0,
-            v1: key.rotate_by_32() ^ mul1,
+            v0: lanes ^ mul0,
+            v1: lanes.rotate_by_32() ^ mul1,
             mul0,
             mul1,
             buffer: HashPacket::default(),
+            key: Some(key),
         }
     }
 
@@ -116,7 +175,56 @@ impl AvxHash {
     #[must_use]
     #[target_feature(enable = "avx2")]
     pub unsafe fn force_from_checkpoint(data: [u8; 164]) -> Self {
-        let portable = PortableHash::from_checkpoint(data);
+        Self::force_from_portable(&PortableHash::from_checkpoint(data))
+    }
+
+    /// Creates a new `AvxHash` from a checkpoint if the avx2 feature is detected.
+    #[must_use]
+    pub fn from_checkpoint(data: [u8; 164]) -> Option<Self> {
+        #[cfg(feature = "std")]
+        {
+            if is_x86_feature_detected!("avx2") {
+                Some(unsafe { Self::force_from_checkpoint(data) })
+            } else {
+                None
+            }
+        }
+
+        #[cfg(not(feature = "std"))]
+        {
+            let _ = data;
+            None
+        }
+    }
+
+    /// Creates a new `AvxHash` from a checkpoint and the key it was created with while
+    /// circumventing the runtime check for avx2. A checkpoint does not record the key, so
+    /// supplying it here lets [`HighwayHashReset::reset`] restore the keyed initial state.
+    ///
+    /// # Safety
+    ///
+    /// See [`Self::force_new`] for safety concerns.
+    #[must_use]
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn force_from_checkpoint_with_key(data: [u8; 164], key: Key) -> Self {
+        Self {
+            key: Some(key),
+            ..Self::force_from_checkpoint(data)
+        }
+    }
+
+    /// Create a new `AvxHash` from a checkpoint and the key it was created with if the avx2
+    /// feature is detected
+    #[must_use]
+    pub fn from_checkpoint_with_key(data: [u8; 164], key: Key) -> Option<Self> {
+        Self::from_checkpoint(data).map(|hasher| Self {
+            key: Some(key),
+            ..hasher
+        })
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn force_from_portable(portable: &PortableHash) -> Self {
         AvxHash {
             v0: V4x64U::new(
                 portable.v0[3],
@@ -143,25 +251,7 @@ impl AvxHash {
                 portable.mul1[0],
             ),
             buffer: portable.buffer,
-        }
-    }
-
-    /// Creates a new `AvxHash` from a checkpoint if the avx2 feature is detected.
-    #[must_use]
-    pub fn from_checkpoint(data: [u8; 164]) -> Option<Self> {
-        #[cfg(feature = "std")]
-        {
-            if is_x86_feature_detected!("avx2") {
-                Some(unsafe { Self::force_from_checkpoint(data) })
-            } else {
-                None
-            }
-        }
-
-        #[cfg(not(feature = "std"))]
-        {
-            let _ = data;
-            None
+            key: portable.key,
         }
     }
 
@@ -232,7 +322,7 @@ impl AvxHash {
     #[target_feature(enable = "avx2")]
     unsafe fn remainder(bytes: &[u8]) -> V4x64U {
         let size_mod32 = bytes.len();
-        let size256 = _mm256_broadcastd_epi32(_mm_cvtsi64_si128(size_mod32 as i64));
+        let size256 = _mm256_broadcastd_epi32(_mm_cvtsi32_si128(size_mod32 as i32));
         let size_mod4 = size_mod32 & 3;
         let size = _mm256_castsi256_si128(size256);
         if size_mod32 & 16 != 0 {
@@ -251,7 +341,7 @@ impl AvxHash {
             let packetL = _mm_maskload_epi32(bytes.as_ptr().cast::<i32>(), int_mask);
             let remainder = &bytes[size_mod32 & !3..];
             let last3 = unordered_load3(remainder);
-            let packetH = _mm_cvtsi64_si128(last3 as i64);
+            let packetH = _mm_cvtsi32_si128(last3 as i32);
             let packetL256 = _mm256_castsi128_si256(packetL);
             let packet = _mm256_inserti128_si256(packetL256, packetH, 1);
             V4x64U::from(packet)
@@ -261,7 +351,7 @@ impl AvxHash {
     #[target_feature(enable = "avx2")]
     unsafe fn update_remainder(&mut self) {
         let size = self.buffer.len();
-        let size256 = _mm256_broadcastd_epi32(_mm_cvtsi64_si128(size as i64));
+        let size256 = _mm256_broadcastd_epi32(_mm_cvtsi32_si128(size as i32));
         self.v0 += V4x64U::from(size256);
         let shifted_left = V4x64U::from(_mm256_sllv_epi32(self.v1.0, size256));
         let tip = _mm256_broadcastd_epi32(_mm_cvtsi32_si128(32));
@@ -338,6 +428,13 @@ impl AvxHash {
             self.buffer.set_to(chunks.remainder());
         }
     }
+
+    #[target_feature(enable = "avx2")]
+    unsafe fn append_vectored<T: Deref<Target = [u8]>>(&mut self, fragments: &[T]) {
+        let mut buffer = self.buffer;
+        buffer.assemble(fragments, |packet| self.update(Self::data_to_lanes(packet)));
+        self.buffer = buffer;
+    }
 }
 
 impl_write!(AvxHash);
@@ -3,13 +3,17 @@ use super::v2x64u::V2x64U;
 use crate::internal::unordered_load3;
 use crate::internal::{HashPacket, PACKET_SIZE};
 use crate::key::Key;
-use crate::traits::HighwayHash;
+use crate::traits::{HighwayHash, HighwayHashReset, ResetError};
 use crate::PortableHash;
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
 use core::arch::x86_64::*;
+use core::ops::Deref;
 
-/// SSE empowered implementation that will only work on `x86_64` with sse 4.1 enabled at the CPU
-/// level.
-#[derive(Debug, Default, Clone)]
+/// SSE empowered implementation that will only work on `x86` and `x86_64` with sse 4.1 enabled at
+/// the CPU level.
+#[derive(Debug, Clone, Copy)]
 pub struct SseHash {
     v0L: V2x64U,
     v0H: V2x64U,
@@ -20,6 +24,7 @@ pub struct SseHash {
     mul1L: V2x64U,
     mul1H: V2x64U,
     buffer: HashPacket,
+    pub(crate) key: Option<Key>,
 }
 
 impl HighwayHash for SseHash {
@@ -30,6 +35,13 @@ impl HighwayHash for SseHash {
         }
     }
 
+    #[inline]
+    fn append_vectored<T: Deref<Target = [u8]>>(&mut self, fragments: &[T]) {
+        unsafe {
+            self.append_vectored(fragments);
+        }
+    }
+
     #[inline]
     fn finalize64(mut self) -> u64 {
         unsafe { Self::finalize64(&mut self) }
@@ -69,11 +81,57 @@ impl HighwayHash for SseHash {
             mul0,
             mul1,
             buffer: self.buffer,
+            key: self.key,
         }
         .checkpoint()
     }
 }
 
+impl Default for SseHash {
+    fn default() -> Self {
+        // Only sound where sse4.1 is available, as was the zeroed state this replaced
+        unsafe { SseHash::force_new(Key::default()) }
+    }
+}
+
+impl HighwayHashReset for SseHash {
+    #[inline]
+    fn finalize64_reset(&mut self) -> Result<u64, ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        let hash = unsafe { Self::finalize64(self) };
+        self.rekey(key);
+        Ok(hash)
+    }
+
+    #[inline]
+    fn finalize128_reset(&mut self) -> Result<[u64; 2], ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        let hash = unsafe { Self::finalize128(self) };
+        self.rekey(key);
+        Ok(hash)
+    }
+
+    #[inline]
+    fn finalize256_reset(&mut self) -> Result<[u64; 4], ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        let hash = unsafe { Self::finalize256(self) };
+        self.rekey(key);
+        Ok(hash)
+    }
+
+    #[inline]
+    fn reset(&mut self) -> Result<(), ResetError> {
+        let key = self.key.ok_or(ResetError(()))?;
+        self.rekey(key);
+        Ok(())
+    }
+
+    #[inline]
+    fn rekey(&mut self, key: Key) {
+        *self = unsafe { Self::force_new(key) };
+    }
+}
+
 impl SseHash {
     /// Creates a new `SseHash` while circumventing the runtime check for sse4.1.
     ///
@@ -103,6 +161,7 @@ impl SseHash {
             mul1L: init1L,
             mul1H: init1H,
             buffer: HashPacket::default(),
+            key: Some(key),
         }
     }
 
@@ -133,18 +192,7 @@ impl SseHash {
     #[must_use]
     #[target_feature(enable = "sse4.1")]
     pub unsafe fn force_from_checkpoint(data: [u8; 164]) -> Self {
-        let portable = PortableHash::from_checkpoint(data);
-        SseHash {
-            v0L: V2x64U::new(portable.v0[1], portable.v0[0]),
-            v0H: V2x64U::new(portable.v0[3], portable.v0[2]),
-            v1L: V2x64U::new(portable.v1[1], portable.v1[0]),
-            v1H: V2x64U::new(portable.v1[3], portable.v1[2]),
-            mul0L: V2x64U::new(portable.mul0[1], portable.mul0[0]),
-            mul0H: V2x64U::new(portable.mul0[3], portable.mul0[2]),
-            mul1L: V2x64U::new(portable.mul1[1], portable.mul1[0]),
-            mul1H: V2x64U::new(portable.mul1[3], portable.mul1[2]),
-            buffer: portable.buffer,
-        }
+        Self::force_from_portable(&PortableHash::from_checkpoint(data))
     }
 
     /// Create a new `SseHash` from a checkpoint if the sse4.1 feature is detected
@@ -166,6 +214,48 @@ impl SseHash {
         }
     }
 
+    /// Creates a new `SseHash` from a checkpoint and the key it was created with while
+    /// circumventing the runtime check for sse4.1. A checkpoint does not record the key, so
+    /// supplying it here lets [`HighwayHashReset::reset`] restore the keyed initial state.
+    ///
+    /// # Safety
+    ///
+    /// See [`Self::force_new`] for safety concerns.
+    #[must_use]
+    #[target_feature(enable = "sse4.1")]
+    pub unsafe fn force_from_checkpoint_with_key(data: [u8; 164], key: Key) -> Self {
+        Self {
+            key: Some(key),
+            ..Self::force_from_checkpoint(data)
+        }
+    }
+
+    /// Create a new `SseHash` from a checkpoint and the key it was created with if the sse4.1
+    /// feature is detected
+    #[must_use]
+    pub fn from_checkpoint_with_key(data: [u8; 164], key: Key) -> Option<Self> {
+        Self::from_checkpoint(data).map(|hasher| Self {
+            key: Some(key),
+            ..hasher
+        })
+    }
+
+    #[target_feature(enable = "sse4.1")]
+    pub(crate) unsafe fn force_from_portable(portable: &PortableHash) -> Self {
+        SseHash {
+            v0L: V2x64U::new(portable.v0[1], portable.v0[0]),
+            v0H: V2x64U::new(portable.v0[3], portable.v0[2]),
+            v1L: V2x64U::new(portable.v1[1], portable.v1[0]),
+            v1H: V2x64U::new(portable.v1[3], portable.v1[2]),
+            mul0L: V2x64U::new(portable.mul0[1], portable.mul0[0]),
+            mul0H: V2x64U::new(portable.mul0[3], portable.mul0[2]),
+            mul1L: V2x64U::new(portable.mul1[1], portable.mul1[0]),
+            mul1H: V2x64U::new(portable.mul1[3], portable.mul1[2]),
+            buffer: portable.buffer,
+            key: portable.key,
+        }
+    }
+
     #[target_feature(enable = "sse4.1")]
     unsafe fn zipper_merge(v: &V2x64U) -> V2x64U {
         v.shuffle(&V2x64U::new(0x0708_0609_0D0A_040B, 0x000F_010E_0502_0C03))
@@ -272,7 +362,7 @@ impl SseHash {
     #[target_feature(enable = "sse4.1")]
     unsafe fn load_multiple_of_four(bytes: &[u8]) -> V2x64U {
         let mut data = bytes;
-        let mut mask4 = V2x64U::from(_mm_cvtsi64_si128(0xFFFF_FFFF));
+        let mut mask4 = V2x64U::from(_mm_cvtsi32_si128(-1));
         let mut ret = if bytes.len() >= 8 {
             mask4 = V2x64U::from(_mm_slli_si128(mask4.0, 8));
             data = &bytes[8..];
@@ -306,7 +396,7 @@ impl SseHash {
             let remainder = &bytes[size_mod32 & !3..];
             let packetL = SseHash::load_multiple_of_four(bytes);
             let last4 = unordered_load3(remainder);
-            let packetH = V2x64U::from(_mm_cvtsi64_si128(last4 as i64));
+            let packetH = V2x64U::from(_mm_cvtsi32_si128(last4 as i32));
             (packetH, packetL)
         }
     }
@@ -317,17 +407,17 @@ impl SseHash {
         let vsize_mod32 = _mm_set1_epi32(size as i32);
         self.v0L += V2x64U::from(vsize_mod32);
         self.v0H += V2x64U::from(vsize_mod32);
-        self.rotate_32_by(size as i64);
+        self.rotate_32_by(size as i32);
         let packet = SseHash::remainder(self.buffer.as_slice());
         self.update(packet);
     }
 
     #[target_feature(enable = "sse4.1")]
-    unsafe fn rotate_32_by(&mut self, count: i64) {
+    unsafe fn rotate_32_by(&mut self, count: i32) {
         let vL = &mut self.v1L;
         let vH = &mut self.v1H;
-        let count_left = _mm_cvtsi64_si128(count);
-        let count_right = _mm_cvtsi64_si128(32 - count);
+        let count_left = _mm_cvtsi32_si128(count);
+        let count_right = _mm_cvtsi32_si128(32 - count);
         let shifted_leftL = V2x64U::from(_mm_sll_epi32(vL.0, count_left));
         let shifted_leftH = V2x64U::from(_mm_sll_epi32(vH.0, count_left));
         let shifted_rightL = V2x64U::from(_mm_srl_epi32(vL.0, count_right));
@@ -364,6 +454,13 @@ impl SseHash {
             self.buffer.set_to(chunks.remainder());
         }
     }
+
+    #[target_feature(enable = "sse4.1")]
+    unsafe fn append_vectored<T: Deref<Target = [u8]>>(&mut self, fragments: &[T]) {
+        let mut buffer = self.buffer;
+        buffer.assemble(fragments, |packet| self.update(Self::data_to_lanes(packet)));
+        self.buffer = buffer;
+    }
 }
 
 impl_write!(SseHash);
@@ -0,0 +1,81 @@
+use crate::portable::PortableHash;
+
+/// Reads extendable output from a finalized hasher. Created by
+/// [`HighwayHash::finalize_xof`](crate::HighwayHash::finalize_xof).
+///
+/// After the usual 10 finalization rounds, each 32 byte block is squeezed from all four lane
+/// groups with the same reduction as `finalize256`, and 4 more permutation rounds are run
+/// between blocks. This construction is not part of the HighwayHash specification, so the
+/// output should only be relied upon by consumers of this crate. It is computed with the
+/// portable implementation so that every backend yields the same bytes.
+///
+/// ```rust
+/// use highway::{HighwayHash, HighwayHasher, Key};
+/// let key = Key([1, 2, 3, 4]);
+/// let mut hasher = HighwayHasher::new(key);
+/// hasher.append(b"subkeys");
+///
+/// let mut output = [0u8; 100];
+/// hasher.finalize_xof().fill(&mut output);
+///
+/// let hash = HighwayHasher::new(key).hash256(b"subkeys");
+/// assert_eq!(&output[..8], &hash[0].to_le_bytes());
+/// ```
+#[derive(Debug, Clone)]
+pub struct XofReader {
+    hasher: PortableHash,
+    block: [u8; 32],
+    position: usize,
+}
+
+impl XofReader {
+    pub(crate) fn new(checkpoint: [u8; 164]) -> Self {
+        let mut hasher = PortableHash::from_checkpoint(checkpoint);
+        let block = Self::to_bytes(hasher.finalize256());
+        XofReader {
+            hasher,
+            block,
+            position: 0,
+        }
+    }
+
+    fn to_bytes(words: [u64; 4]) -> [u8; 32] {
+        let mut result = [0u8; 32];
+        for (dest, word) in result.chunks_exact_mut(8).zip(words.iter()) {
+            dest.copy_from_slice(&word.to_le_bytes());
+        }
+        result
+    }
+
+    fn squeeze(&mut self) {
+        self.hasher.permute_rounds(4);
+        self.block = Self::to_bytes(self.hasher.reduce256());
+        self.position = 0;
+    }
+
+    /// Fills `output` with the next bytes of the stream. Output is the same no matter how
+    /// reads are split.
+    pub fn fill(&mut self, output: &mut [u8]) {
+        let mut output = output;
+        while !output.is_empty() {
+            if self.position == self.block.len() {
+                self.squeeze();
+            }
+
+            let available = &self.block[self.position..];
+            let len = available.len().min(output.len());
+            let (head, tail) = output.split_at_mut(len);
+            head.copy_from_slice(&available[..len]);
+            self.position += len;
+            output = tail;
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Read for XofReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.fill(buf);
+        Ok(buf.len())
+    }
+}
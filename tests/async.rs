@@ -0,0 +1,99 @@
+#![cfg(all(
+    feature = "std",
+    any(feature = "tokio", feature = "futures-io", feature = "futures-core"),
+    not(target_family = "wasm")
+))]
+
+use highway::{HighwayHash, HighwayHasher, Key, PortableHash};
+
+fn data() -> Vec<u8> {
+    (0..100_000u32).map(|x| (x % 251) as u8).collect()
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn tokio_hash_async_reader() {
+    let key = Key([1, 2, 3, 4]);
+    let data = data();
+    let hash = highway::tokio::hash_async_reader(data.as_slice(), key)
+        .await
+        .unwrap();
+    assert_eq!(hash, PortableHash::new(key).hash256(&data));
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn tokio_async_write() {
+    let key = Key([1, 2, 3, 4]);
+    let data = data();
+    let mut hasher = HighwayHasher::new(key);
+    tokio::io::copy(&mut data.as_slice(), &mut hasher)
+        .await
+        .unwrap();
+    assert_eq!(hasher.finalize256(), PortableHash::new(key).hash256(&data));
+
+    let mut hasher = PortableHash::new(key);
+    tokio::io::AsyncWriteExt::write_all(&mut hasher, &data)
+        .await
+        .unwrap();
+    assert_eq!(hasher.finalize64(), PortableHash::new(key).hash64(&data));
+}
+
+#[cfg(feature = "futures-io")]
+#[test]
+fn futures_hash_async_reader() {
+    let key = Key([1, 2, 3, 4]);
+    let data = data();
+    let hash =
+        futures::executor::block_on(highway::futures::hash_async_reader(data.as_slice(), key))
+            .unwrap();
+    assert_eq!(hash, PortableHash::new(key).hash256(&data));
+}
+
+#[cfg(feature = "futures-io")]
+#[test]
+fn futures_async_write() {
+    let key = Key([1, 2, 3, 4]);
+    let data = data();
+    let mut hasher = HighwayHasher::new(key);
+    futures::executor::block_on(futures::io::copy(data.as_slice(), &mut hasher)).unwrap();
+    assert_eq!(hasher.finalize256(), PortableHash::new(key).hash256(&data));
+}
+
+#[cfg(feature = "futures-core")]
+#[test]
+fn hashing_stream() {
+    use futures::StreamExt;
+    use highway::futures::{HashingStream, HashingTryStream};
+
+    let key = Key([1, 2, 3, 4]);
+    let data = data();
+    let chunks = data.chunks(1000).map(|x| x.to_vec()).collect::<Vec<_>>();
+
+    let stream = HashingStream::new(
+        futures::stream::iter(chunks.clone()),
+        HighwayHasher::new(key),
+    );
+    let (passed, stream) = futures::executor::block_on(async {
+        let mut stream = stream;
+        let mut passed = Vec::new();
+        while let Some(chunk) = stream.next().await {
+            passed.extend_from_slice(&chunk);
+        }
+        (passed, stream)
+    });
+    assert_eq!(passed, data);
+    assert_eq!(stream.finalize256(), PortableHash::new(key).hash256(&data));
+
+    let items = chunks
+        .into_iter()
+        .map(Ok::<_, std::io::Error>)
+        .collect::<Vec<_>>();
+    let mut stream = HashingTryStream::new(futures::stream::iter(items), PortableHash::new(key));
+    futures::executor::block_on(async {
+        while let Some(chunk) = stream.next().await {
+            chunk.unwrap();
+        }
+    });
+    assert_eq!(stream.finalize64(), PortableHash::new(key).hash64(&data));
+}
@@ -0,0 +1,103 @@
+#![cfg(feature = "std")]
+
+use highway::bloom::{BloomFilter, CountingBloomFilter, HashFamily, Indices, MAX_HASHES};
+use highway::{HighwayHash, HighwayHasher, Key};
+
+#[test]
+fn family_indices() {
+    let key = Key([1, 2, 3, 4]);
+    let family = HashFamily::new(key);
+    let indices: Vec<u64> = family.indices(b"hello", 7, 1000).collect();
+    assert_eq!(indices.len(), 7);
+    assert!(indices.iter().all(|&i| i < 1000));
+
+    let hash = HighwayHasher::new(key).hash256(b"hello");
+    assert!(Indices::from_hash(hash, 7, 1000).eq(indices.iter().copied()));
+
+    let xof: Vec<u64> = family.xof_indices(b"hello", 1000).take(100).collect();
+    assert!(xof.iter().all(|&i| i < 1000));
+    assert!(family.xof_indices(b"hello", 1000).take(100).eq(xof));
+
+    assert_eq!(
+        family.indices(b"hello", 3, 1).collect::<Vec<_>>(),
+        [0, 0, 0]
+    );
+    assert!(family.indices(b"hello", 64, u64::MAX).all(|i| i < u64::MAX));
+}
+
+#[test]
+fn bloom_filter() {
+    let mut filter = BloomFilter::with_false_positive_rate(Key([1, 2, 3, 4]), 1000, 0.01);
+    for i in 0..1000u32 {
+        filter.insert(&i.to_le_bytes());
+    }
+
+    assert!((0..1000u32).all(|i| filter.contains(&i.to_le_bytes())));
+    let false_positives = (1000..11000u32)
+        .filter(|i| filter.contains(&i.to_le_bytes()))
+        .count();
+    assert!(false_positives < 200, "{}", false_positives);
+
+    let restored = BloomFilter::from_bytes(filter.key(), &filter.to_bytes()).unwrap();
+    assert_eq!(restored.key(), filter.key());
+    assert_eq!(restored.num_bits(), filter.num_bits());
+    assert_eq!(restored.num_hashes(), filter.num_hashes());
+    assert!((0..1000u32).all(|i| restored.contains(&i.to_le_bytes())));
+
+    filter.clear();
+    assert!(!filter.contains(&0u32.to_le_bytes()));
+}
+
+#[test]
+fn bloom_filter_rejects_malformed_bytes() {
+    let key = Key::default();
+    let filter = BloomFilter::new(key, 100, 3);
+    let bytes = filter.to_bytes();
+    assert!(BloomFilter::from_bytes(key, &bytes[..bytes.len() - 1]).is_err());
+    assert!(BloomFilter::from_bytes(key, &bytes[..10]).is_err());
+    assert!(CountingBloomFilter::from_bytes(key, &bytes).is_err());
+
+    // The number of hashes lives at bytes 12..16 of the header
+    for num_hashes in [0, MAX_HASHES + 1, u32::MAX] {
+        let mut bytes = bytes.clone();
+        bytes[12..16].copy_from_slice(&num_hashes.to_le_bytes());
+        assert!(BloomFilter::from_bytes(key, &bytes).is_err());
+    }
+}
+
+#[test]
+fn bloom_filter_bytes_exclude_key() {
+    let key = Key([0x0101_0101_0101_0101; 4]);
+    let bytes = BloomFilter::new(key, 64, 3).to_bytes();
+    assert!(!bytes.windows(8).any(|w| w == key[0].to_le_bytes()));
+}
+
+#[test]
+#[should_panic(expected = "invalid number of hashes")]
+fn bloom_filter_rejects_zero_hashes() {
+    let _ = BloomFilter::new(Key::default(), 64, 0);
+}
+
+#[test]
+#[should_panic(expected = "false positive rate")]
+fn bloom_filter_rejects_zero_false_positive_rate() {
+    let _ = BloomFilter::with_false_positive_rate(Key::default(), 100, 0.0);
+}
+
+#[test]
+fn counting_bloom_filter() {
+    let mut filter = CountingBloomFilter::new(Key([1, 2, 3, 4]), 1024, 4);
+    filter.insert(b"a");
+    filter.insert(b"a");
+    filter.insert(b"b");
+    assert!(filter.count(b"a") >= 2);
+    assert!(!filter.remove(b"c"));
+
+    assert!(filter.remove(b"b"));
+    assert!(!filter.contains(b"b"));
+    assert!(filter.contains(b"a"));
+
+    let restored = CountingBloomFilter::from_bytes(filter.key(), &filter.to_bytes()).unwrap();
+    assert_eq!(restored.count(b"a"), filter.count(b"a"));
+    assert_eq!(restored.num_counters(), 1024);
+}
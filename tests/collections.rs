@@ -0,0 +1,62 @@
+#![cfg(any(feature = "hashbrown", feature = "indexmap"))]
+
+use highway::collections::HighwayCollection;
+use highway::{HighwayBuildHasher, Key};
+
+#[cfg(feature = "hashbrown")]
+#[test]
+fn hashbrown_aliases() {
+    use highway::collections::{HighwayHashMap, HighwayHashSet};
+
+    let key = Key::new([1, 2, 3, 4]);
+    let mut map: HighwayHashMap<&str, u32> = HighwayHashMap::with_key(key);
+    map.insert("a", 1);
+    map.insert("b", 2);
+    assert_eq!(map.get("b"), Some(&2));
+    assert_eq!(map.hasher(), &HighwayBuildHasher::new(key));
+
+    let mut set: HighwayHashSet<u64> = HighwayHashSet::with_capacity_and_key(16, key);
+    assert!(set.capacity() >= 16);
+    assert!(set.insert(1));
+    assert!(!set.insert(1));
+}
+
+#[cfg(feature = "indexmap")]
+#[test]
+fn indexmap_aliases() {
+    use highway::collections::{HighwayIndexMap, HighwayIndexSet};
+
+    let mut map: HighwayIndexMap<&str, u32> =
+        HighwayIndexMap::with_random_key(|bytes| bytes.fill(7));
+    map.insert("b", 2);
+    map.insert("a", 1);
+    assert_eq!(map.get_index(0), Some((&"b", &2)));
+    assert_eq!(map.hasher().key(), Key::from_bytes([7; 32]));
+
+    let mut set: HighwayIndexSet<u64> = HighwayIndexSet::with_key(Key::default());
+    set.insert(3);
+    assert_eq!(set.get_index_of(&3), Some(0));
+}
+
+#[test]
+fn build_hasher_is_copy() {
+    let builder = HighwayBuildHasher::new(Key::new([1, 2, 3, 4]));
+    let copy = builder;
+    assert_eq!(builder, copy);
+    assert_ne!(builder, HighwayBuildHasher::default());
+}
+
+#[test]
+fn build_hasher_from_prepared() {
+    use core::hash::BuildHasher;
+    use highway::{HighwayHash, HighwayHasher, PreparedKey};
+
+    let key = Key::new([1, 2, 3, 4]);
+    let builder = HighwayBuildHasher::from_prepared(PreparedKey::new(key));
+    assert_eq!(builder, HighwayBuildHasher::new(key));
+    assert_eq!(builder.key(), key);
+    assert_eq!(
+        builder.build_hasher().hash64(b"hello"),
+        HighwayHasher::new(key).hash64(b"hello")
+    );
+}
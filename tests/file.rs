@@ -0,0 +1,55 @@
+#![cfg(feature = "std")]
+
+use highway::{HighwayHash, Key, PortableHash};
+use std::io::Write;
+
+fn data(len: usize) -> Vec<u8> {
+    (0..len).map(|x| (x % 251) as u8).collect()
+}
+
+#[test]
+fn hash_file_matches_in_memory() {
+    let key = Key([1, 2, 3, 4]);
+    let dir = std::env::temp_dir();
+
+    // Cover empty, sub-packet, unaligned and larger than both the read buffer and the mmap
+    // threshold
+    for (i, len) in [0, 31, 32, 65_537, 3 * 1024 * 1024 + 5].iter().enumerate() {
+        let data = data(*len);
+        let path = dir.join(format!("highway-hash-file-{}-{}", std::process::id(), i));
+        std::fs::File::create(&path)
+            .and_then(|mut f| f.write_all(&data))
+            .unwrap();
+
+        let hash = highway::hash_file(&path, key);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(hash.unwrap(), PortableHash::new(key).hash256(&data));
+    }
+}
+
+#[test]
+fn hash_file_missing() {
+    let path = std::env::temp_dir().join("highway-hash-file-does-not-exist");
+    assert!(highway::hash_file(path, Key::default()).is_err());
+}
+
+/// A reader that trickles out data in uneven chunks like a pipe
+struct Trickle<'a>(&'a [u8], usize);
+
+impl std::io::Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.1 += 1;
+        let len = (self.1 * 7 % 1000).min(buf.len()).min(self.0.len());
+        buf[..len].copy_from_slice(&self.0[..len]);
+        self.0 = &self.0[len..];
+        Ok(len)
+    }
+}
+
+#[test]
+fn hash_reader_short_reads() {
+    let key = Key([1, 2, 3, 4]);
+    let data = data(200_000);
+    let hash = highway::hash_reader(Trickle(&data, 0), key).unwrap();
+    assert_eq!(hash, PortableHash::new(key).hash256(&data));
+}
@@ -1,4 +1,4 @@
-use highway::{HighwayHash, HighwayHasher, Key, PortableHash};
+use highway::{hash64_const, HighwayHash, HighwayHasher, Key, PortableHash};
 
 #[test]
 fn hash_zeroes() {
@@ -7,6 +7,62 @@ fn hash_zeroes() {
     assert_eq!(0x7035_DA75_B9D5_4469, hash);
 }
 
+#[test]
+fn const_hash_compile_time() {
+    const KEY: Key = Key::new([1, 2, 3, 4]);
+    const ZEROES: u64 = hash64_const(Key::new([0, 0, 0, 0]), &[]);
+    const SIMPLE: u64 = hash64_const(KEY, &[0xff]);
+    assert_eq!(0x7035_DA75_B9D5_4469, ZEROES);
+    assert_eq!(0x7858_f24d_2d79_b2b2, SIMPLE);
+}
+
+#[test]
+fn key_from_bytes() {
+    let mut bytes = [0u8; 32];
+    bytes[0] = 1;
+    bytes[8] = 2;
+    bytes[23] = 0x80;
+    bytes[24] = 4;
+    assert_eq!(
+        Key::from_bytes(bytes),
+        Key([1, 2, 0x8000_0000_0000_0000, 4])
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn key_random() {
+    assert_ne!(Key::random(), Key::random());
+
+    let key = Key::random();
+    assert_ne!(key[0], key[1]);
+    assert_ne!(key[2], key[3]);
+}
+
+#[test]
+fn xof_known_output() {
+    let key = Key([1, 2, 3, 4]);
+    let b: Vec<u8> = (0..33).map(|x| 128 + x as u8).collect();
+    let mut hasher = HighwayHasher::new(key);
+    hasher.append(&b);
+
+    let mut output = [0u8; 64];
+    hasher.finalize_xof().fill(&mut output);
+    let expected: [u64; 8] = [
+        0x9715_ff40_3359_953e,
+        0x6a22_0041_539c_80ee,
+        0xd0b4_ccd3_b1af_ee88,
+        0xdbbb_60b2_dca6_cab0,
+        0x8b44_23b9_3bbe_852b,
+        0xc727_1652_7188_6cf3,
+        0x7b53_aa4b_f16e_ae86,
+        0x40f6_f571_52bc_f273,
+    ];
+    for (chunk, word) in output.chunks_exact(8).zip(expected.iter()) {
+        assert_eq!(chunk, &word.to_le_bytes()[..]);
+    }
+}
+
 #[test]
 fn portable_hash_simple() {
     let key = Key([1, 2, 3, 4]);
@@ -487,7 +543,7 @@ fn u64_to_u256(data: &[u64]) -> (u128, u128) {
     (u64_to_u128(data), u64_to_u128(&data[2..]))
 }
 
-#[cfg(target_arch = "x86_64")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 #[test]
 fn sse_hash_zeroes() {
     use highway::SseHash;
@@ -501,7 +557,7 @@ fn sse_hash_zeroes() {
     assert_eq!(0x7035_DA75_B9D5_4469, hash);
 }
 
-#[cfg(target_arch = "x86_64")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 #[test]
 fn sse_hash_eq_portable() {
     use highway::SseHash;
@@ -546,7 +602,7 @@ fn sse_hash_eq_portable() {
 }
 
 #[test]
-#[cfg(target_arch = "x86_64")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 fn avx_hash_eq_portable() {
     use highway::AvxHash;
     if !is_x86_feature_detected!("avx2") {
@@ -590,7 +646,7 @@ fn portable_survive_crash() {
 }
 
 #[test]
-#[cfg(target_arch = "x86_64")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 fn avx_survive_crash() {
     use highway::AvxHash;
     if !is_x86_feature_detected!("avx2") {
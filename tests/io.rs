@@ -0,0 +1,103 @@
+#![cfg(feature = "std")]
+
+use highway::io::{ExpectedHash, HashingReader, HashingWriter, VerifyingReader};
+use highway::{HighwayHash, HighwayHasher, Key, PortableHash};
+use std::io::{ErrorKind, Read, Write};
+
+fn data() -> Vec<u8> {
+    (0..10_000u32).map(|x| (x % 251) as u8).collect()
+}
+
+#[test]
+fn hashing_reader_tees_into_hasher() {
+    let key = Key([1, 2, 3, 4]);
+    let data = data();
+    let mut reader = HashingReader::new(data.as_slice(), PortableHash::new(key));
+    let mut out = Vec::new();
+    std::io::copy(&mut reader, &mut out).unwrap();
+    assert_eq!(out, data);
+    assert_eq!(
+        reader.into_inner().1.finalize256(),
+        PortableHash::new(key).hash256(&data)
+    );
+}
+
+#[test]
+fn hashing_writer_tees_into_hasher() {
+    let key = Key([1, 2, 3, 4]);
+    let data = data();
+    let mut writer = HashingWriter::new(Vec::new(), HighwayHasher::new(key));
+    for chunk in data.chunks(33) {
+        writer.write_all(chunk).unwrap();
+    }
+    writer.flush().unwrap();
+
+    let (out, hasher) = writer.into_inner();
+    assert_eq!(out, data);
+    assert_eq!(hasher.finalize128(), PortableHash::new(key).hash128(&data));
+}
+
+#[test]
+fn hashing_writer_only_hashes_accepted_bytes() {
+    let data = data();
+    let mut buf = [0u8; 100];
+    let mut writer = HashingWriter::new(&mut buf[..], HighwayHasher::default());
+    assert_eq!(writer.write(&data).unwrap(), 100);
+    let (_, hasher) = writer.into_inner();
+    assert_eq!(
+        hasher.finalize64(),
+        HighwayHasher::default().hash64(&data[..100])
+    );
+}
+
+#[test]
+fn verifying_reader_accepts_all_widths() {
+    let key = Key([1, 2, 3, 4]);
+    let data = data();
+    let expected: [ExpectedHash; 3] = [
+        PortableHash::new(key).hash64(&data).into(),
+        PortableHash::new(key).hash128(&data).into(),
+        PortableHash::new(key).hash256(&data).into(),
+    ];
+
+    for hash in expected.iter() {
+        let mut reader = VerifyingReader::new(data.as_slice(), HighwayHasher::new(key), *hash);
+        let mut out = Vec::new();
+        reader.read_to_end(&mut out).unwrap();
+        assert!(reader.is_verified());
+        assert_eq!(out, data);
+    }
+}
+
+#[test]
+fn verifying_reader_rejects_mismatch() {
+    let key = Key([1, 2, 3, 4]);
+    let data = data();
+    let expected = PortableHash::new(key).hash64(&data);
+    let mut reader = VerifyingReader::new(&data[1..], HighwayHasher::new(key), expected);
+    let mut out = Vec::new();
+    let err = reader.read_to_end(&mut out).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert!(!reader.is_verified());
+
+    // The error is sticky
+    let err = reader.read(&mut [0u8; 10]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn verifying_reader_accepts_keyless_hashers() {
+    let data = data();
+    let expected = PortableHash::default().hash64(&data);
+    let mut reader = VerifyingReader::new(data.as_slice(), PortableHash::default(), expected);
+    reader.read_to_end(&mut Vec::new()).unwrap();
+    assert!(reader.is_verified());
+
+    let mut hasher = PortableHash::new(Key([1, 2, 3, 4]));
+    hasher.append(&data[..100]);
+    let expected = hasher.hash64(&data[100..]);
+    let restored = PortableHash::from_checkpoint(hasher.checkpoint());
+    let mut reader = VerifyingReader::new(&data[100..], restored, expected);
+    reader.read_to_end(&mut Vec::new()).unwrap();
+    assert!(reader.is_verified());
+}
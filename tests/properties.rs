@@ -2,7 +2,11 @@
 extern crate quickcheck_macros;
 
 mod quick_tests {
-    use highway::{HighwayHash, HighwayHasher, Key, PortableHash};
+    use highway::{
+        hash128_const, hash256_const, hash64_const, HighwayBuildHasher, HighwayHash,
+        HighwayHashReset, HighwayHasher, Key, MultisetHash, Portable32Hash, PortableHash,
+    };
+    use std::hash::BuildHasher;
 
     #[quickcheck]
     fn portable64_eq(k1: u64, k2: u64, k3: u64, k4: u64, data: Vec<u8>) -> bool {
@@ -28,6 +32,30 @@ mod quick_tests {
         hash1 == hash2
     }
 
+    #[quickcheck]
+    fn portable32_64_eq(k1: u64, k2: u64, k3: u64, k4: u64, data: Vec<u8>) -> bool {
+        let key = Key([k1, k2, k3, k4]);
+        let hash1 = PortableHash::new(key).hash64(data.as_slice());
+        let hash2 = Portable32Hash::new(key).hash64(data.as_slice());
+        hash1 == hash2
+    }
+
+    #[quickcheck]
+    fn portable32_128_eq(k1: u64, k2: u64, k3: u64, k4: u64, data: Vec<u8>) -> bool {
+        let key = Key([k1, k2, k3, k4]);
+        let hash1 = PortableHash::new(key).hash128(data.as_slice());
+        let hash2 = Portable32Hash::new(key).hash128(data.as_slice());
+        hash1 == hash2
+    }
+
+    #[quickcheck]
+    fn portable32_256_eq(k1: u64, k2: u64, k3: u64, k4: u64, data: Vec<u8>) -> bool {
+        let key = Key([k1, k2, k3, k4]);
+        let hash1 = PortableHash::new(key).hash256(data.as_slice());
+        let hash2 = Portable32Hash::new(key).hash256(data.as_slice());
+        hash1 == hash2
+    }
+
     #[quickcheck]
     fn builder64_eq(k1: u64, k2: u64, k3: u64, k4: u64, data: Vec<u8>) -> bool {
         let key = Key([k1, k2, k3, k4]);
@@ -58,7 +86,7 @@ mod quick_tests {
         let hash1 = PortableHash::new(key).hash64(data.as_slice());
         let hash2 = HighwayHasher::new(key).hash64(data.as_slice());
 
-        #[cfg(target_arch = "x86_64")]
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
         {
             use highway::{AvxHash, SseHash};
             let mut res = hash1 == hash2;
@@ -72,7 +100,7 @@ mod quick_tests {
             res
         }
 
-        #[cfg(not(target_arch = "x86_64"))]
+        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
         {
             hash1 == hash2
         }
@@ -84,7 +112,7 @@ mod quick_tests {
         let hash1 = PortableHash::new(key).hash128(data.as_slice());
         let hash2 = HighwayHasher::new(key).hash128(data.as_slice());
 
-        #[cfg(target_arch = "x86_64")]
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
         {
             use highway::{AvxHash, SseHash};
             let mut res = hash1 == hash2;
@@ -98,7 +126,7 @@ mod quick_tests {
             res
         }
 
-        #[cfg(not(target_arch = "x86_64"))]
+        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
         {
             hash1 == hash2
         }
@@ -110,7 +138,7 @@ mod quick_tests {
         let hash1 = PortableHash::new(key).hash256(data.as_slice());
         let hash2 = HighwayHasher::new(key).hash256(data.as_slice());
 
-        #[cfg(target_arch = "x86_64")]
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
         {
             use highway::{AvxHash, SseHash};
             let mut res = hash1 == hash2;
@@ -124,12 +152,316 @@ mod quick_tests {
             res
         }
 
-        #[cfg(not(target_arch = "x86_64"))]
+        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
         {
             hash1 == hash2
         }
     }
 
+    #[quickcheck]
+    fn fragments_eq(k1: u64, k2: u64, k3: u64, k4: u64, data: Vec<u8>, cuts: Vec<usize>) {
+        let key = Key([k1, k2, k3, k4]);
+        let mut cuts: Vec<usize> = cuts.iter().map(|x| x % (data.len() + 1)).collect();
+        cuts.sort_unstable();
+
+        let mut fragments = Vec::new();
+        let mut start = 0;
+        for cut in cuts {
+            fragments.push(&data[start..cut]);
+            start = cut;
+        }
+        fragments.push(&data[start..]);
+
+        let hash1 = PortableHash::new(key).hash256(data.as_slice());
+        let hash2 = PortableHash::new(key).hash256_fragments(&fragments);
+        assert_eq!(hash1, hash2);
+
+        let hash2 = Portable32Hash::new(key).hash256_fragments(&fragments);
+        assert_eq!(hash1, hash2);
+
+        let owned: Vec<Vec<u8>> = fragments.iter().map(|x| x.to_vec()).collect();
+        let hash2 = HighwayHasher::new(key).hash256_fragments(&owned);
+        assert_eq!(hash1, hash2);
+
+        let hash2 = HighwayHasher::new(key).hash256_fragments(&fragments);
+        assert_eq!(hash1, hash2);
+
+        let hash1 = PortableHash::new(key).hash64(data.as_slice());
+        let hash2 = HighwayHasher::new(key).hash64_fragments(&fragments);
+        assert_eq!(hash1, hash2);
+
+        let hash1 = PortableHash::new(key).hash128(data.as_slice());
+        let hash2 = HighwayHasher::new(key).hash128_fragments(&fragments);
+        assert_eq!(hash1, hash2);
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            use highway::{AvxHash, SseHash};
+            let hash1 = PortableHash::new(key).hash256(data.as_slice());
+            if let Some(h) = AvxHash::new(key) {
+                assert_eq!(hash1, h.hash256_fragments(&fragments));
+            }
+
+            if let Some(h) = SseHash::new(key) {
+                assert_eq!(hash1, h.hash256_fragments(&fragments));
+            }
+        }
+    }
+
+    fn write_ints<H: core::hash::Hasher>(mut hasher: H, ints: &[(u8, u16, u32, u64, u128)]) -> u64 {
+        for (a, b, c, d, e) in ints {
+            hasher.write_u8(*a);
+            hasher.write_u16(*b);
+            hasher.write_u32(*c);
+            hasher.write_u64(*d);
+            hasher.write_u128(*e);
+            hasher.write_usize(*d as usize);
+            hasher.write_i64(*d as i64);
+        }
+        hasher.finish()
+    }
+
+    fn write_bytes<H: core::hash::Hasher>(
+        mut hasher: H,
+        ints: &[(u8, u16, u32, u64, u128)],
+    ) -> u64 {
+        for (a, b, c, d, e) in ints {
+            hasher.write(&a.to_ne_bytes());
+            hasher.write(&b.to_ne_bytes());
+            hasher.write(&c.to_ne_bytes());
+            hasher.write(&d.to_ne_bytes());
+            hasher.write(&e.to_ne_bytes());
+            hasher.write(&(*d as usize).to_ne_bytes());
+            hasher.write(&(*d as i64).to_ne_bytes());
+        }
+        hasher.finish()
+    }
+
+    #[quickcheck]
+    fn hasher_ints_eq(k1: u64, k2: u64, k3: u64, k4: u64, ints: Vec<(u8, u16, u32, u64, u128)>) {
+        let key = Key([k1, k2, k3, k4]);
+        let expected = write_bytes(PortableHash::new(key), &ints);
+        assert_eq!(expected, write_ints(PortableHash::new(key), &ints));
+        assert_eq!(expected, write_ints(Portable32Hash::new(key), &ints));
+        assert_eq!(expected, write_ints(HighwayHasher::new(key), &ints));
+
+        let builder = HighwayBuildHasher::new(key);
+        assert_eq!(expected, write_ints(builder.build_hasher(), &ints));
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            use highway::{AvxHash, SseHash};
+            if let Some(h) = AvxHash::new(key) {
+                assert_eq!(expected, write_ints(h, &ints));
+            }
+
+            if let Some(h) = SseHash::new(key) {
+                assert_eq!(expected, write_ints(h, &ints));
+            }
+        }
+    }
+
+    fn reuse<H: HighwayHashReset>(mut hasher: H, key: Key, other: Key, a: &[u8], b: &[u8]) {
+        let expected = PortableHash::new(key);
+        hasher.append(a);
+        assert_eq!(hasher.finalize64_reset(), Ok(expected.hash64(a)));
+        hasher.append(b);
+        assert_eq!(hasher.finalize128_reset(), Ok(expected.hash128(b)));
+        hasher.append(a);
+        hasher.reset().unwrap();
+        hasher.append(b);
+        assert_eq!(hasher.finalize256_reset(), Ok(expected.hash256(b)));
+
+        hasher.append(a);
+        hasher.rekey(other);
+        hasher.append(b);
+        assert_eq!(
+            hasher.finalize256_reset(),
+            Ok(PortableHash::new(other).hash256(b))
+        );
+        hasher.append(a);
+        assert_eq!(
+            hasher.finalize64_reset(),
+            Ok(PortableHash::new(other).hash64(a))
+        );
+    }
+
+    #[quickcheck]
+    fn reset_eq(k1: u64, k2: u64, k3: u64, k4: u64, a: Vec<u8>, b: Vec<u8>) {
+        let key = Key([k1, k2, k3, k4]);
+        let other = Key([k4, k3, k2, k1]);
+        reuse(PortableHash::new(key), key, other, &a, &b);
+        reuse(Portable32Hash::new(key), key, other, &a, &b);
+        reuse(HighwayHasher::new(key), key, other, &a, &b);
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            use highway::{AvxHash, SseHash};
+            if let Some(h) = AvxHash::new(key) {
+                reuse(h, key, other, &a, &b);
+            }
+
+            if let Some(h) = SseHash::new(key) {
+                reuse(h, key, other, &a, &b);
+            }
+        }
+
+        let checkpoint = HighwayHasher::new(key).checkpoint();
+        let mut hasher = HighwayHasher::from_checkpoint_with_key(checkpoint, key);
+        hasher.append(&a);
+        hasher.reset().unwrap();
+        assert_eq!(hasher.hash64(&b), HighwayHasher::new(key).hash64(&b));
+
+        let mut hasher = PortableHash::from_checkpoint_with_key(checkpoint, key);
+        hasher.append(&a);
+        hasher.reset().unwrap();
+        assert_eq!(hasher.hash64(&b), PortableHash::new(key).hash64(&b));
+
+        let mut hasher = Portable32Hash::from_checkpoint_with_key(checkpoint, key);
+        hasher.append(&a);
+        hasher.reset().unwrap();
+        assert_eq!(hasher.hash64(&b), Portable32Hash::new(key).hash64(&b));
+    }
+
+    #[test]
+    fn reset_keyless_checkpoint_fails() {
+        let checkpoint = HighwayHasher::new(Key([1, 2, 3, 4])).checkpoint();
+        let mut hasher = HighwayHasher::from_checkpoint(checkpoint);
+        hasher.append(b"hello");
+        let expected = hasher.checkpoint();
+        assert!(hasher.reset().is_err());
+        assert!(hasher.finalize64_reset().is_err());
+        assert_eq!(hasher.checkpoint(), expected);
+    }
+
+    #[test]
+    fn default_hashers_reset() {
+        fn check<H: HighwayHashReset + Default>() {
+            let mut hasher = H::default();
+            hasher.append(b"hello");
+            let expected = PortableHash::new(Key::default()).hash64(b"hello");
+            assert_eq!(hasher.finalize64_reset(), Ok(expected));
+            hasher.append(b"hello");
+            assert_eq!(hasher.finalize64_reset(), Ok(expected));
+        }
+
+        check::<PortableHash>();
+        check::<Portable32Hash>();
+        check::<HighwayHasher>();
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if is_x86_feature_detected!("avx2") {
+                check::<highway::AvxHash>();
+            }
+
+            if is_x86_feature_detected!("sse4.1") {
+                check::<highway::SseHash>();
+            }
+        }
+    }
+
+    #[quickcheck]
+    fn append_le_eq(k1: u64, k2: u64, k3: u64, k4: u64, ints: Vec<u32>, longs: Vec<u64>) {
+        let key = Key([k1, k2, k3, k4]);
+        let mut bytes = Vec::new();
+        bytes.extend(ints.iter().flat_map(|x| x.to_le_bytes()));
+        bytes.extend(longs.iter().flat_map(|x| x.to_le_bytes()));
+        let expected = PortableHash::new(key).hash256(&bytes);
+
+        let mut hasher = HighwayHasher::new(key);
+        hasher.append_u32_le(&ints);
+        hasher.append_u64_le(&longs);
+        assert_eq!(hasher.finalize256(), expected);
+
+        let mut hasher = PortableHash::new(key);
+        hasher.append_u32_le(&ints);
+        hasher.append_u64_le(&longs);
+        assert_eq!(hasher.finalize256(), expected);
+    }
+
+    #[quickcheck]
+    fn append_float_le_eq(k1: u64, k2: u64, k3: u64, k4: u64, floats: Vec<f32>, doubles: Vec<f64>) {
+        let key = Key([k1, k2, k3, k4]);
+        let mut bytes = Vec::new();
+        bytes.extend(floats.iter().flat_map(|x| x.to_bits().to_le_bytes()));
+        bytes.extend(doubles.iter().flat_map(|x| x.to_bits().to_le_bytes()));
+        let expected = PortableHash::new(key).hash256(&bytes);
+
+        let mut hasher = HighwayHasher::new(key);
+        hasher.append_f32_le(&floats);
+        hasher.append_f64_le(&doubles);
+        assert_eq!(hasher.finalize256(), expected);
+    }
+
+    #[quickcheck]
+    fn const_eq(k1: u64, k2: u64, k3: u64, k4: u64, data: Vec<u8>) {
+        let key = Key::new([k1, k2, k3, k4]);
+        let hasher = HighwayHasher::new(key);
+        assert_eq!(hash64_const(key, &data), hasher.hash64(&data));
+        assert_eq!(hash128_const(key, &data), hasher.hash128(&data));
+        assert_eq!(hash256_const(key, &data), hasher.hash256(&data));
+    }
+
+    #[quickcheck]
+    fn xof_eq(k1: u64, k2: u64, k3: u64, k4: u64, data: Vec<u8>, split: u8) {
+        let key = Key([k1, k2, k3, k4]);
+        let mut expected = [0u8; 200];
+        let mut reader = PortableHash::new(key);
+        reader.append(&data);
+        reader.finalize_xof().fill(&mut expected);
+
+        let hash = PortableHash::new(key).hash256(&data);
+        for (chunk, word) in expected.chunks_exact(8).zip(hash.iter()) {
+            assert_eq!(chunk, &word.to_le_bytes()[..]);
+        }
+
+        let mut output = [0u8; 200];
+        let mut hasher = HighwayHasher::new(key);
+        hasher.append(&data);
+        let mut reader = hasher.finalize_xof();
+        let (head, tail) = output.split_at_mut(usize::from(split) % 200);
+        reader.fill(head);
+        reader.fill(tail);
+        assert_eq!(&output[..], &expected[..]);
+
+        let mut output = [0u8; 200];
+        let mut hasher = Portable32Hash::new(key);
+        hasher.append(&data);
+        hasher.finalize_xof().fill(&mut output);
+        assert_eq!(&output[..], &expected[..]);
+    }
+
+    #[quickcheck]
+    fn multiset_eq(k1: u64, k2: u64, k3: u64, k4: u64, elements: Vec<Vec<u8>>, extra: Vec<u8>) {
+        let key = Key([k1, k2, k3, k4]);
+        let mut forward = MultisetHash::new(key);
+        for element in &elements {
+            forward.insert(element);
+        }
+
+        let mut reversed = MultisetHash::new(key);
+        reversed.insert(&extra);
+        for element in elements.iter().rev() {
+            reversed.insert(element);
+        }
+        reversed.remove(&extra);
+        assert_eq!(forward, reversed);
+        assert_eq!(forward.digest(), reversed.digest());
+
+        let (head, tail) = elements.split_at(elements.len() / 2);
+        let mut merged = MultisetHash::new(key);
+        let mut other = MultisetHash::new(key);
+        head.iter().for_each(|element| merged.insert(element));
+        tail.iter().for_each(|element| other.insert(element));
+        merged.merge(&other);
+        assert_eq!(merged.digest(), forward.digest());
+        assert_eq!(merged.len(), elements.len() as u64);
+
+        forward.insert(&extra);
+        assert_ne!(forward.digest(), reversed.digest());
+    }
+
     #[quickcheck]
     fn checkpoint_eq(k1: u64, k2: u64, k3: u64, k4: u64, data: Vec<u8>) {
         let key = Key([k1, k2, k3, k4]);
@@ -143,13 +475,19 @@ mod quick_tests {
         snd.append(tail);
         assert_eq!(hash1.as_slice(), snd.finalize256().as_slice());
 
+        let mut hasher = Portable32Hash::new(key);
+        hasher.append(head);
+        let mut snd = Portable32Hash::from_checkpoint(hasher.checkpoint());
+        snd.append(tail);
+        assert_eq!(hash1.as_slice(), snd.finalize256().as_slice());
+
         let mut hasher = HighwayHasher::new(key);
         hasher.append(head);
         let mut snd = HighwayHasher::from_checkpoint(hasher.checkpoint());
         snd.append(tail);
         assert_eq!(hash1.as_slice(), snd.finalize256().as_slice());
 
-        #[cfg(target_arch = "x86_64")]
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
         {
             use highway::SseHash;
             if let Some(mut hasher) = SseHash::new(key) {
@@ -162,7 +500,7 @@ mod quick_tests {
     }
 }
 
-#[cfg(target_arch = "x86_64")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod quick_simd_tests {
     use highway::{AvxHash, HighwayHash, Key, SseHash};
 
@@ -0,0 +1,78 @@
+#![cfg(feature = "std")]
+
+use highway::pseudonym::{Pseudonymizer, TokenEncoding};
+use highway::{HighwayHash, HighwayHasher, Key};
+
+#[test]
+fn token_layout() {
+    let pseudonymizer = Pseudonymizer::new(Key([1, 2, 3, 4]), 3);
+    let token = pseudonymizer.token("user_id", b"alice");
+    assert_eq!(token.len(), 3 + 26);
+    assert!(token.starts_with("v3_"));
+    assert!(token[3..]
+        .bytes()
+        .all(|b| b.is_ascii_lowercase() || (b'2'..=b'7').contains(&b)));
+
+    let mut hasher = HighwayHasher::new(Key([1, 2, 3, 4]));
+    hasher.append(&7u64.to_le_bytes());
+    hasher.append(b"user_id");
+    hasher.append(b"alice");
+    let [low, high] = hasher.finalize128();
+    assert_eq!(pseudonymizer.raw_token("user_id", b"alice"), [low, high]);
+
+    let hex = pseudonymizer.with_encoding(TokenEncoding::Hex);
+    let expected = format!("v3_{:016x}{:016x}", low.swap_bytes(), high.swap_bytes());
+    assert_eq!(hex.token("user_id", b"alice"), expected);
+}
+
+#[test]
+fn contexts_are_unlinkable() {
+    let pseudonymizer = Pseudonymizer::new(Key([1, 2, 3, 4]), 1);
+    assert_ne!(
+        pseudonymizer.token("user_id", b"42"),
+        pseudonymizer.token("account_id", b"42")
+    );
+    assert_ne!(
+        pseudonymizer.token("ab", b"c"),
+        pseudonymizer.token("a", b"bc")
+    );
+    assert_eq!(
+        pseudonymizer.token_u64("user_id", 42),
+        pseudonymizer.token("user_id", &42u64.to_le_bytes())
+    );
+    assert_eq!(
+        pseudonymizer.token_i64("user_id", -1),
+        pseudonymizer.token_u64("user_id", u64::MAX)
+    );
+}
+
+#[test]
+fn batches_match_single_tokens() {
+    let pseudonymizer = Pseudonymizer::new(Key([1, 2, 3, 4]), 1);
+    let values = ["alice", "bob", "carol"];
+    let tokens = pseudonymizer.tokens("user_id", values);
+    let single: Vec<String> = values
+        .iter()
+        .map(|value| pseudonymizer.token("user_id", value.as_bytes()))
+        .collect();
+    assert_eq!(tokens, single);
+}
+
+#[test]
+fn key_rotation() {
+    let mut pseudonymizer = Pseudonymizer::new(Key([1, 2, 3, 4]), 1);
+    let old = pseudonymizer.token("email", b"alice@example.com");
+
+    pseudonymizer.rotate(Key([5, 6, 7, 8]), 2);
+    assert_eq!(pseudonymizer.version(), 2);
+    let new = pseudonymizer.token("email", b"alice@example.com");
+    assert_ne!(old[3..], new[3..]);
+    assert!(pseudonymizer.matches(&old, "email", b"alice@example.com"));
+    assert!(pseudonymizer.matches(&new, "email", b"alice@example.com"));
+    assert!(!pseudonymizer.matches(&new, "email", b"bob@example.com"));
+    assert!(!pseudonymizer.matches("garbage", "email", b"alice@example.com"));
+
+    assert!(!pseudonymizer.retire(2));
+    assert!(pseudonymizer.retire(1));
+    assert!(!pseudonymizer.matches(&old, "email", b"alice@example.com"));
+}
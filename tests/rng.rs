@@ -0,0 +1,45 @@
+#![cfg(feature = "rand_core")]
+
+use highway::{HighwayHash, HighwayRng, Key, PortableHash};
+use rand_core::{RngCore, SeedableRng};
+
+#[test]
+fn rng_counter_mode() {
+    let key = Key([1, 2, 3, 4]);
+    let mut rng = HighwayRng::new(key);
+    for counter in 0..3u64 {
+        let expected = PortableHash::new(key).hash256(&counter.to_le_bytes());
+        for word in expected {
+            assert_eq!(rng.next_u64(), word);
+        }
+    }
+}
+
+#[test]
+fn rng_seek() {
+    let mut rng = HighwayRng::new(Key([1, 2, 3, 4]));
+    let outputs: Vec<u64> = (0..12).map(|_| rng.next_u64()).collect();
+    assert_eq!(rng.block_pos(), 3);
+
+    rng.seek(1);
+    assert_eq!(rng.block_pos(), 1);
+    assert_eq!(rng.next_u64(), outputs[4]);
+    assert_eq!(rng.block_pos(), 1);
+
+    rng.seek(u64::MAX);
+    let expected = PortableHash::new(Key([1, 2, 3, 4])).hash256(&u64::MAX.to_le_bytes());
+    assert_eq!(rng.next_u64(), expected[0]);
+}
+
+#[test]
+fn rng_fill_bytes() {
+    let mut rng = HighwayRng::from_seed([7; 32]);
+    let mut other = HighwayRng::new(Key::from_bytes([7; 32]));
+
+    let mut bytes = [0u8; 20];
+    rng.fill_bytes(&mut bytes);
+    assert_eq!(bytes[..8], other.next_u64().to_le_bytes());
+    assert_eq!(bytes[8..16], other.next_u64().to_le_bytes());
+    assert_eq!(bytes[16..], other.next_u64().to_le_bytes()[..4]);
+    assert_eq!(rng.next_u32(), other.next_u64() as u32);
+}
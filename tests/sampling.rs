@@ -0,0 +1,44 @@
+use highway::sampling::{bucket, salted_unit, should_sample, unit};
+use highway::{HighwayHash, HighwayHasher, Key};
+
+const KEY: Key = Key([1, 2, 3, 4]);
+
+#[test]
+fn documented_vectors() {
+    assert_eq!(
+        HighwayHasher::new(KEY).hash64(b"user-1"),
+        0xd638_834e_8d4f_9567
+    );
+    assert_eq!(unit(KEY, b"user-1"), 0.8367998186852772);
+    assert_eq!(unit(KEY, b"user-2"), 0.2008944313648815);
+    assert_eq!(salted_unit(KEY, b"checkout", b"user-1"), 0.4394875479724081);
+    assert_eq!(bucket(KEY, b"checkout", b"user-1", 10), 5);
+    assert_eq!(bucket(KEY, b"checkout", b"user-2", 10), 2);
+}
+
+#[test]
+fn sampling_rates() {
+    assert!(should_sample(KEY, b"user-2", 0.25));
+    assert!(!should_sample(KEY, b"user-1", 0.25));
+    assert!(should_sample(KEY, b"user-1", 1.0));
+    assert!(!should_sample(KEY, b"user-2", 0.0));
+
+    let sampled = (0..10_000u32)
+        .filter(|i| should_sample(KEY, &i.to_le_bytes(), 0.1))
+        .count();
+    assert!(sampled > 900 && sampled < 1100, "{}", sampled);
+}
+
+#[test]
+fn buckets_are_salted() {
+    let same = (0..1_000u32)
+        .filter(|i| {
+            let id = i.to_le_bytes();
+            bucket(KEY, b"a", &id, 4) == bucket(KEY, b"b", &id, 4)
+        })
+        .count();
+    assert!(same > 150 && same < 350, "{}", same);
+
+    // The salt is length prefixed, so moving bytes between salt and id changes the result
+    assert_ne!(salted_unit(KEY, b"ab", b"c"), salted_unit(KEY, b"a", b"bc"));
+}
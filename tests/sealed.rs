@@ -0,0 +1,93 @@
+#![cfg(feature = "std")]
+
+use highway::sealed::{open, seal, seal_into, OpenError, TAG_SIZE};
+use highway::Key;
+
+const KEY: Key = Key([1, 2, 3, 4]);
+
+#[test]
+fn seal_and_open() {
+    for payload in [&b""[..], b"a", b"telemetry frame"] {
+        let frame = seal(KEY, b"aad", payload);
+        assert_eq!(frame.len(), payload.len() + TAG_SIZE);
+        assert_eq!(&frame[..payload.len()], payload);
+        assert_eq!(open(KEY, b"aad", &frame), Ok(payload));
+    }
+
+    let mut out = b"prefix".to_vec();
+    seal_into(KEY, b"aad", b"payload", &mut out);
+    assert_eq!(open(KEY, b"aad", &out[6..]), Ok(&b"payload"[..]));
+}
+
+#[test]
+fn rejects_forgeries() {
+    let frame = seal(KEY, b"aad", b"payload");
+    assert_eq!(
+        open(Key([5, 6, 7, 8]), b"aad", &frame),
+        Err(OpenError::InvalidTag)
+    );
+    assert_eq!(open(KEY, b"other", &frame), Err(OpenError::InvalidTag));
+    assert_eq!(
+        open(KEY, b"aad", &frame[..TAG_SIZE - 1]),
+        Err(OpenError::TooShort)
+    );
+
+    for i in 0..frame.len() {
+        let mut tampered = frame.clone();
+        tampered[i] ^= 1;
+        assert_eq!(open(KEY, b"aad", &tampered), Err(OpenError::InvalidTag));
+    }
+
+    // Moving bytes between the associated data and the payload changes the tag
+    let frame = seal(KEY, b"ab", b"c");
+    let mut moved = b"bc".to_vec();
+    moved.extend_from_slice(&frame[1..]);
+    assert_eq!(open(KEY, b"a", &moved), Err(OpenError::InvalidTag));
+}
+
+#[cfg(feature = "tokio-util")]
+mod codec {
+    use super::KEY;
+    use highway::sealed::SealedCodec;
+    use std::io;
+    use tokio_util::bytes::BytesMut;
+    use tokio_util::codec::{Decoder, Encoder};
+
+    #[test]
+    fn round_trip_partial_frames() {
+        let mut codec = SealedCodec::new(KEY, b"stream-1");
+        let mut wire = BytesMut::new();
+        codec.encode(&b"first"[..], &mut wire).unwrap();
+        codec.encode(b"second".to_vec(), &mut wire).unwrap();
+
+        let mut received = BytesMut::new();
+        let mut payloads = Vec::new();
+        for byte in wire.iter() {
+            received.extend_from_slice(&[*byte]);
+            while let Some(payload) = codec.decode(&mut received).unwrap() {
+                payloads.push(payload);
+            }
+        }
+        assert_eq!(payloads, [&b"first"[..], &b"second"[..]]);
+        assert!(received.is_empty());
+    }
+
+    #[test]
+    fn rejects_invalid_frames() {
+        let mut codec = SealedCodec::new(KEY, b"stream-1");
+        let mut wire = BytesMut::new();
+        codec.encode(&b"payload"[..], &mut wire).unwrap();
+
+        let mut other = SealedCodec::new(KEY, b"stream-2");
+        let err = other.decode(&mut wire.clone()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        let mut tampered = wire.clone();
+        tampered[5] ^= 1;
+        assert!(codec.decode(&mut tampered).is_err());
+
+        let mut small = SealedCodec::new(KEY, b"stream-1").max_frame_length(8);
+        assert!(small.decode(&mut wire).is_err());
+        assert!(small.encode(&b"payload"[..], &mut BytesMut::new()).is_err());
+    }
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "std")]
+
+use highway::shard::{hash_to_range, jump_bucket, rendezvous, weighted_rendezvous, HashRing};
+use highway::Key;
+
+const KEY: Key = Key([1, 2, 3, 4]);
+
+fn ids() -> impl Iterator<Item = [u8; 4]> {
+    (0..10_000u32).map(|i| i.to_le_bytes())
+}
+
+#[test]
+fn jump_bucket_moves_only_to_new_bucket() {
+    let mut counts = [0u32; 10];
+    for id in ids() {
+        let before = jump_bucket(KEY, &id, 10);
+        let after = jump_bucket(KEY, &id, 11);
+        assert!(after == before || after == 10);
+        counts[before as usize] += 1;
+    }
+    assert!(counts.iter().all(|&count| count > 800 && count < 1200));
+    assert_eq!(jump_bucket(KEY, b"id", 1), 0);
+}
+
+#[test]
+fn hash_to_range_is_bounded() {
+    let mut counts = [0u32; 3];
+    for id in ids() {
+        counts[hash_to_range(KEY, &id, 3) as usize] += 1;
+    }
+    assert!(counts.iter().all(|&count| count > 3000 && count < 3700));
+    assert_eq!(hash_to_range(KEY, b"id", 1), 0);
+    assert!(hash_to_range(KEY, b"id", u64::MAX) < u64::MAX);
+}
+
+#[test]
+fn rendezvous_minimal_disruption() {
+    let nodes = ["a", "b", "c", "d"];
+    assert_eq!(rendezvous::<&str>(KEY, b"id", &[]), None);
+    for id in ids() {
+        let owner = rendezvous(KEY, &id, &nodes).unwrap();
+        let remaining: Vec<&str> = nodes.iter().copied().filter(|n| n != owner).collect();
+        let other = nodes.iter().find(|n| *n != owner).unwrap();
+        let without: Vec<&str> = nodes.iter().copied().filter(|n| n != other).collect();
+        assert_eq!(rendezvous(KEY, &id, &without).unwrap(), owner);
+        assert_ne!(rendezvous(KEY, &id, &remaining).unwrap(), owner);
+    }
+}
+
+#[test]
+fn weighted_rendezvous_proportional() {
+    let nodes = [("a", 1.0), ("b", 3.0), ("c", 0.0)];
+    let heavy = ids()
+        .filter(|id| *weighted_rendezvous(KEY, id, &nodes).unwrap() == "b")
+        .count();
+    assert!(heavy > 7000 && heavy < 8000, "{}", heavy);
+    assert_eq!(weighted_rendezvous(KEY, b"id", &[("a", 0.0)]), None);
+}
+
+#[test]
+fn hash_ring() {
+    let mut ring = HashRing::new(KEY, 64);
+    assert!(ring.get(b"id").is_none());
+    for node in ["a", "b", "c"] {
+        ring.add(node);
+    }
+
+    let before: Vec<&str> = ids().map(|id| *ring.get(&id).unwrap()).collect();
+    assert!(["a", "b", "c"]
+        .iter()
+        .all(|node| before.iter().filter(|n| *n == node).count() > 2000));
+
+    assert_eq!(ring.remove(b"b"), Some("b"));
+    assert_eq!(ring.remove(b"b"), None);
+    for (id, owner) in ids().zip(before) {
+        let now = *ring.get(&id).unwrap();
+        assert!(owner == "b" || now == owner);
+    }
+    assert_eq!(ring.nodes(), ["a", "c"]);
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "std")]
+
+use highway::sketch::{hamming_distance, hamming_similarity, MinHasher, SimHasher};
+use highway::{HighwayHash, HighwayHasher, Key};
+
+#[test]
+fn minhash_signature() {
+    let hasher = MinHasher::new(Key([1, 2, 3, 4]), 6);
+    let signature = hasher.signature(["a", "b"]);
+    assert_eq!(signature.as_slice().len(), 6);
+
+    let lanes = |shingle: &[u8], block: u64| {
+        let mut h = HighwayHasher::new(Key([1, 2, 3, 4]));
+        h.append(shingle);
+        h.append(&block.to_le_bytes());
+        h.finalize256()
+    };
+    let (a0, a1, b0, b1) = (
+        lanes(b"a", 0),
+        lanes(b"a", 1),
+        lanes(b"b", 0),
+        lanes(b"b", 1),
+    );
+    let expected: Vec<u64> = (0..4)
+        .map(|i| a0[i].min(b0[i]))
+        .chain((0..2).map(|i| a1[i].min(b1[i])))
+        .collect();
+    assert_eq!(signature.as_slice(), &expected[..]);
+}
+
+#[test]
+fn minhash_jaccard() {
+    let hasher = MinHasher::new(Key([1, 2, 3, 4]), 256);
+    let a: Vec<u32> = (0..1000).collect();
+    let b: Vec<u32> = (500..1500).collect();
+    let sig_a = hasher.signature(a.iter().map(|x| x.to_le_bytes()));
+    let sig_b = hasher.signature(b.iter().map(|x| x.to_le_bytes()));
+
+    // True Jaccard similarity is 500 / 1500
+    let estimate = sig_a.jaccard(&sig_b);
+    assert!((estimate - 1.0 / 3.0).abs() < 0.1, "{}", estimate);
+    assert_eq!(sig_a.jaccard(&sig_a), 1.0);
+
+    let mut merged = hasher.signature(a[..500].iter().map(|x| x.to_le_bytes()));
+    merged.merge(&hasher.signature(a[500..].iter().map(|x| x.to_le_bytes())));
+    assert_eq!(merged, sig_a);
+}
+
+#[test]
+fn simhash() {
+    let hasher = SimHasher::new(Key([1, 2, 3, 4]));
+    let words = |s: &'static str| s.split(' ');
+    let a = hasher.hash(words("a b c d e f g h i j k l m n o p"));
+    let b = hasher.hash(words("a b c d e f g h i j k l m n o q"));
+    let c = hasher.hash(words("q r s t u v w x y z aa bb cc dd ee ff"));
+    assert!(hamming_distance(a, b) < hamming_distance(a, c));
+    assert_eq!(hamming_similarity(a, a), 1.0);
+
+    let single = HighwayHasher::new(Key([1, 2, 3, 4])).hash64(b"x");
+    assert_eq!(hasher.hash(["x"]), single);
+    assert_eq!(
+        hasher.hash_weighted([("x", 2), ("y", 1)]),
+        hasher.hash(["x", "x", "y"])
+    );
+}
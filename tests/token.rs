@@ -0,0 +1,65 @@
+#![cfg(feature = "std")]
+
+use highway::token::{TokenError, TokenSigner};
+use highway::Key;
+
+#[test]
+fn sign_and_verify() {
+    let signer = TokenSigner::new(Key([1, 2, 3, 4]), "session");
+    for payload in [&b""[..], b"a", b"ab", b"abc", b"user=42;role=admin"] {
+        let token = signer.sign(payload, 100, 200);
+        assert!(token
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_'));
+
+        let verified = signer.verify(&token, 150).unwrap();
+        assert_eq!(verified.payload(), payload);
+        assert_eq!(verified.issued_at(), 100);
+        assert_eq!(verified.expires_at(), 200);
+        assert_eq!(signer.verify(&token, 200), Err(TokenError::Expired));
+        assert_eq!(signer.verify(&token, 99), Err(TokenError::NotYetValid));
+    }
+}
+
+#[test]
+fn rejects_tampering() {
+    let signer = TokenSigner::new(Key([1, 2, 3, 4]), "session");
+    let token = signer.sign(b"user=42", 100, 200);
+
+    for i in 0..token.len() {
+        let mut tampered = token.clone().into_bytes();
+        tampered[i] = if tampered[i] == b'A' { b'B' } else { b'A' };
+        let tampered = String::from_utf8(tampered).unwrap();
+        assert!(signer.verify(&tampered, 150).is_err(), "{}", i);
+    }
+
+    assert_eq!(signer.verify("", 150), Err(TokenError::Malformed));
+    assert_eq!(
+        signer.verify("not base64!", 150),
+        Err(TokenError::Malformed)
+    );
+    assert!(signer.verify(&token[..token.len() - 4], 150).is_err());
+
+    let other = TokenSigner::new(Key([1, 2, 3, 4]), "csrf");
+    assert_eq!(other.verify(&token, 150), Err(TokenError::InvalidTag));
+}
+
+#[test]
+fn key_rotation() {
+    let mut signer = TokenSigner::new(Key([1, 2, 3, 4]), "session");
+    let old = signer.sign(b"user=42", 100, 200);
+
+    signer.rotate(Key([5, 6, 7, 8]));
+    let new = signer.sign(b"user=42", 100, 200);
+    assert_ne!(old, new);
+    assert!(signer.verify(&old, 150).is_ok());
+    assert!(signer.verify(&new, 150).is_ok());
+
+    let previous =
+        TokenSigner::new(Key([5, 6, 7, 8]), "session").with_previous_key(Key([1, 2, 3, 4]));
+    assert!(previous.verify(&old, 150).is_ok());
+
+    signer.retain_keys(1);
+    assert_eq!(signer.verify(&old, 150), Err(TokenError::InvalidTag));
+    assert!(signer.verify(&new, 150).is_ok());
+}
@@ -18,7 +18,7 @@ fn hashers_should_implement_write_and_hasher() {
     assert!(hash::<highway::HighwayHasher>().is_ok());
 }
 
-#[cfg(target_arch = "x86_64")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 #[test]
 fn x86_hashers_should_implement_write_and_hasher() {
     if is_x86_feature_detected!("avx2") {
@@ -28,3 +28,124 @@ fn x86_hashers_should_implement_write_and_hasher() {
         assert!(hash::<highway::SseHash>().is_ok());
     }
 }
+
+#[cfg(target_arch = "aarch64")]
+#[test]
+fn aarch64_hashers_should_implement_write_and_hasher() {
+    assert!(hash::<highway::NeonHash>().is_ok());
+}
+
+#[test]
+fn write_vectored_matches_append() {
+    use highway::{HighwayHash, HighwayHasher, Key};
+    use std::io::{IoSlice, Write};
+
+    let data = (0..100u8).collect::<Vec<_>>();
+    let (head, tail) = data.split_at(33);
+    let (middle, tail) = tail.split_at(17);
+    let bufs = [IoSlice::new(head), IoSlice::new(middle), IoSlice::new(tail)];
+
+    let mut hasher = HighwayHasher::new(Key::default());
+    assert_eq!(hasher.write_vectored(&bufs).unwrap(), data.len());
+
+    let expected = HighwayHasher::new(Key::default()).hash256(&data);
+    assert_eq!(hasher.finalize256(), expected);
+}
+
+#[test]
+fn hashers_should_be_usable_as_trait_objects() {
+    use highway::{DynHighwayHash, HighwayHash, HighwayHasher, Key, PortableHash};
+
+    let key = Key([1, 2, 3, 4]);
+    let mut hashers: Vec<Box<dyn DynHighwayHash>> = vec![
+        Box::new(PortableHash::new(key)),
+        Box::new(highway::Portable32Hash::new(key)),
+        Box::new(HighwayHasher::new(key)),
+    ];
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if let Some(h) = highway::AvxHash::new(key) {
+            hashers.push(Box::new(h));
+        }
+        if let Some(h) = highway::SseHash::new(key) {
+            hashers.push(Box::new(h));
+        }
+    }
+
+    let data = (0..100u8).collect::<Vec<_>>();
+    for hasher in &mut hashers {
+        hasher.dyn_append(&data[..40]);
+        let mut snd = hasher.clone();
+        hasher.dyn_append(&data[40..]);
+        assert_eq!(
+            hasher.dyn_finalize256(),
+            Ok(PortableHash::new(key).hash256(&data))
+        );
+
+        // finalizing resets the hasher
+        hasher.dyn_append(&data);
+        assert_eq!(
+            hasher.dyn_finalize64(),
+            Ok(PortableHash::new(key).hash64(&data))
+        );
+
+        snd.dyn_append(&data[40..]);
+        assert_eq!(
+            snd.dyn_finalize128(),
+            Ok(PortableHash::new(key).hash128(&data))
+        );
+    }
+}
+
+#[test]
+fn default_and_restored_trait_objects_do_not_panic() {
+    use highway::{DynHighwayHash, HighwayHash, Key, PortableHash};
+
+    let mut hasher: Box<dyn DynHighwayHash> = Box::new(PortableHash::default());
+    hasher.dyn_append(b"hello");
+    let expected = PortableHash::new(Key::default()).hash64(b"hello");
+    assert_eq!(hasher.dyn_finalize64(), Ok(expected));
+    hasher.dyn_append(b"hello");
+    assert_eq!(hasher.dyn_finalize64(), Ok(expected));
+
+    // Without its key a restored hasher can't reset, so finalizing fails and leaves it as is
+    let checkpoint = PortableHash::new(Key([1, 2, 3, 4])).checkpoint();
+    let mut hasher: Box<dyn DynHighwayHash> = Box::new(PortableHash::from_checkpoint(checkpoint));
+    hasher.dyn_append(b"hello");
+    let before = hasher.dyn_checkpoint();
+    assert!(hasher.dyn_finalize64().is_err());
+    assert!(hasher.dyn_finalize_xof().is_err());
+    assert_eq!(hasher.dyn_checkpoint(), before);
+}
+
+#[test]
+fn glob_import_leaves_hasher_methods_unambiguous() {
+    use highway::*;
+
+    fn hash_twice<H: HighwayHashReset + Clone + 'static>(mut hasher: H, data: &[u8]) -> u64 {
+        hasher.append(data);
+        let first = hasher.finalize64_reset().unwrap();
+        hasher.append(data);
+        let snd = H::clone(&hasher);
+        hasher.reset().unwrap();
+        assert_eq!(first, snd.finalize64());
+        hasher.append(data);
+        hasher.finalize64()
+    }
+
+    let key = Key([1, 2, 3, 4]);
+    let expected = PortableHash::new(key).hash64(b"hello");
+    assert_eq!(hash_twice(PortableHash::new(key), b"hello"), expected);
+    assert_eq!(hash_twice(HighwayHasher::new(key), b"hello"), expected);
+
+    let mut hasher = HighwayHasher::new(Key::default());
+    hasher.rekey(key);
+    hasher.append(b"hello");
+    assert_eq!(hasher.checkpoint(), {
+        let mut other = HighwayHasher::new(key);
+        other.append(b"hello");
+        other.checkpoint()
+    });
+    assert_eq!(hasher.finalize64(), expected);
+}
@@ -0,0 +1,37 @@
+use highway::{HighwayHash, HighwayHasher, Key, Portable32Hash, PortableHash};
+
+fn fmt_hash<H: HighwayHash + core::fmt::Write>(mut hasher: H) -> u64 {
+    write!(hasher, "temperature={} unit=C", 21.5).unwrap();
+    hasher.finalize64()
+}
+
+#[test]
+fn hashers_should_implement_fmt_write() {
+    let key = Key([1, 2, 3, 4]);
+    let expected = PortableHash::new(key).hash64(b"temperature=21.5 unit=C");
+    assert_eq!(fmt_hash(PortableHash::new(key)), expected);
+    assert_eq!(fmt_hash(Portable32Hash::new(key)), expected);
+    assert_eq!(fmt_hash(HighwayHasher::new(key)), expected);
+}
+
+#[cfg(feature = "embedded-io")]
+#[test]
+fn hashers_should_implement_embedded_io_write() {
+    fn hash<H>(mut hasher: H, data: &[u8]) -> [u64; 4]
+    where
+        H: HighwayHash + embedded_io::Write<Error = core::convert::Infallible>,
+    {
+        let (head, tail) = data.split_at(data.len() / 3);
+        assert_eq!(embedded_io::Write::write(&mut hasher, head), Ok(head.len()));
+        embedded_io::Write::write_all(&mut hasher, tail).unwrap();
+        embedded_io::Write::flush(&mut hasher).unwrap();
+        hasher.finalize256()
+    }
+
+    let key = Key([1, 2, 3, 4]);
+    let data = (0..100u8).collect::<Vec<_>>();
+    let expected = PortableHash::new(key).hash256(&data);
+    assert_eq!(hash(PortableHash::new(key), &data), expected);
+    assert_eq!(hash(Portable32Hash::new(key), &data), expected);
+    assert_eq!(hash(HighwayHasher::new(key), &data), expected);
+}